@@ -0,0 +1,100 @@
+//! Formatting of memory addresses as `0x` prefixed hexadecimal, padded to the pointer width of the target, so
+//! that address logs line up the same way on every target, and of addresses split into pages for debugging
+//! memory management units and flash translation layers.
+//!
+//! ```
+//! use numtoa::addr::{format_addr, ADDR_DIGITS};
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_addr(0x2000_0400, &mut buffer);
+//! assert_eq!(buffer.len() - start, 2 + ADDR_DIGITS);
+//! assert!(buffer[start..].ends_with(b"20000400"));
+//! ```
+
+use core::mem;
+
+use super::rtl::Rtl;
+
+/// The number of hexadecimal digits in an address of the target, such as 8 on 32-bit and 16 on 64-bit targets.
+pub const ADDR_DIGITS: usize = mem::size_of::<usize>() * 2;
+
+/// Writes the address into the end of the byte slice as `0x` followed by upper case hexadecimal digits, padded
+/// with zeros to `ADDR_DIGITS`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_addr(addr: usize, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    rtl.hex_padded(addr as u128, ADDR_DIGITS);
+    rtl.bytes(b"0x");
+    rtl.index()
+}
+
+/// Writes the address which the pointer holds, as `format_addr` does, discarding the metadata of wide pointers
+/// such as the length of a slice. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_ptr<T: ?Sized>(ptr: *const T, string: &mut [u8]) -> usize {
+    format_addr(ptr as *const () as usize, string)
+}
+
+/// Splits the address into the number of its page and its offset within the page, and writes them into the end
+/// of the byte slice as `page:offset` in upper case hexadecimal. The offset is padded with zeros to the digits of
+/// the largest offset, such as three digits for pages of 4 KiB. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the page size is not a power of two, or the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::addr::format_page_offset;
+///
+/// let mut buffer = [0u8; 24];
+/// let start = format_page_offset(0x8001_2345, 4096, &mut buffer);
+/// assert_eq!(&buffer[start..], b"80012:345");
+/// ```
+pub fn format_page_offset(addr: u64, page_size: u64, string: &mut [u8]) -> usize {
+    assert!(page_size.is_power_of_two(), "numtoa: page size must be a power of two");
+    let shift = page_size.trailing_zeros();
+    let offset_digits = (shift as usize).div_ceil(4);
+
+    let mut rtl = Rtl::new(string);
+    rtl.hex_padded(u128::from(addr & (page_size - 1)), offset_digits);
+    rtl.byte(b':');
+    rtl.hex(u128::from(addr >> shift));
+    rtl.index()
+}
+
+#[test]
+fn page_offsets() {
+    let mut buffer = [0u8; 24];
+    let i = format_page_offset(0x1000, 4096, &mut buffer);
+    assert_eq!(&buffer[i..], b"1:000");
+
+    let i = format_page_offset(0x3FF, 512, &mut buffer);
+    assert_eq!(&buffer[i..], b"1:1FF");
+
+    let i = format_page_offset(u64::MAX, 1 << 16, &mut buffer);
+    assert_eq!(&buffer[i..], b"FFFFFFFFFFFF:FFFF");
+}
+
+#[test]
+#[should_panic]
+fn page_size_not_power_of_two() {
+    format_page_offset(0, 3000, &mut [0u8; 24]);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn addresses() {
+    let mut buffer = [0u8; 24];
+    let i = format_addr(0, &mut buffer);
+    assert_eq!(&buffer[i..], b"0x0000000000000000");
+
+    let i = format_addr(usize::MAX, &mut buffer);
+    assert_eq!(&buffer[i..], b"0xFFFFFFFFFFFFFFFF");
+
+    let i = format_ptr(0x8000_1000 as *const [u8; 4] as *const [u8], &mut buffer);
+    assert_eq!(&buffer[i..], b"0x0000000080001000");
+}
@@ -0,0 +1,141 @@
+//! Wrapping of numbers in ANSI color escapes for serial console dashboards, with the color chosen by thresholds,
+//! so that a healthy reading shows in green, a worrying one in yellow, and a critical one in red. The escapes are
+//! written into the buffer along with the digits, ready to be sent to the terminal as they are.
+//!
+//! ```
+//! # #[cfg(feature = "u8")] {
+//! use numtoa::ansi::{numtoa_thresholds, Thresholds};
+//!
+//! // Temperatures from 70 are worrying, and from 85 critical.
+//! let limits = Thresholds { warning: 70u8, critical: 85u8 };
+//! let mut buffer = [0u8; 16];
+//! let start = numtoa_thresholds(72u8, 10, &limits, &mut buffer);
+//! assert_eq!(&buffer[start..], b"\x1b[33m72\x1b[0m");
+//! # }
+//! ```
+
+use super::rtl::{Rtl, SCRATCH_LEN};
+use super::NumToA;
+
+/// The escape which resets the color to the default of the terminal.
+pub const RESET: &[u8] = b"\x1b[0m";
+
+/// The eight standard foreground colors of ANSI terminals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// Written with the escape `ESC[30m`.
+    Black,
+    /// Written with the escape `ESC[31m`, and used for critical readings.
+    Red,
+    /// Written with the escape `ESC[32m`, and used for normal readings.
+    Green,
+    /// Written with the escape `ESC[33m`, and used for worrying readings.
+    Yellow,
+    /// Written with the escape `ESC[34m`.
+    Blue,
+    /// Written with the escape `ESC[35m`.
+    Magenta,
+    /// Written with the escape `ESC[36m`.
+    Cyan,
+    /// Written with the escape `ESC[37m`, and chosen by `Color::default`.
+    #[default]
+    White,
+}
+
+impl Color {
+    /// The escape which sets the foreground to this color.
+    pub fn escape(self) -> &'static [u8] {
+        match self {
+            Color::Black   => b"\x1b[30m",
+            Color::Red     => b"\x1b[31m",
+            Color::Green   => b"\x1b[32m",
+            Color::Yellow  => b"\x1b[33m",
+            Color::Blue    => b"\x1b[34m",
+            Color::Magenta => b"\x1b[35m",
+            Color::Cyan    => b"\x1b[36m",
+            Color::White   => b"\x1b[37m",
+        }
+    }
+}
+
+/// The values at which a reading becomes worrying and then critical. When the warning threshold is above the
+/// critical one, lower readings are worse, as with the charge of a battery.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Thresholds<T> {
+    /// The first value which is shown in yellow.
+    pub warning: T,
+    /// The first value which is shown in red.
+    pub critical: T,
+}
+
+impl<T: PartialOrd> Thresholds<T> {
+    /// The color of the reading, which is green below the warning threshold, yellow from it, and red from the
+    /// critical threshold, or the reverse when lower readings are worse.
+    pub fn color(&self, value: &T) -> Color {
+        let (warning, critical) = if self.warning <= self.critical {
+            (*value >= self.warning, *value >= self.critical)
+        } else {
+            (*value <= self.warning, *value <= self.critical)
+        };
+
+        match (warning, critical) {
+            (_, true)     => Color::Red,
+            (true, false) => Color::Yellow,
+            _             => Color::Green,
+        }
+    }
+}
+
+/// Writes the number in the given base into the end of the byte slice in the given color, followed by the escape
+/// which resets it. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn numtoa_color<T: NumToA<B>, B>(number: T, base: B, color: Color, string: &mut [u8]) -> usize {
+    let mut scratch = [0u8; SCRATCH_LEN];
+    let start = number.numtoa(base, &mut scratch);
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(RESET);
+    rtl.bytes(&scratch[start..]);
+    rtl.bytes(color.escape());
+    rtl.index()
+}
+
+/// Writes the number in the given base into the end of the byte slice in the color which the thresholds give it,
+/// followed by the escape which resets it. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn numtoa_thresholds<T: NumToA<B> + PartialOrd, B>(
+    number: T,
+    base: B,
+    thresholds: &Thresholds<T>,
+    string: &mut [u8],
+) -> usize {
+    let color = thresholds.color(&number);
+    numtoa_color(number, base, color, string)
+}
+
+#[test]
+fn threshold_colors() {
+    let rising = Thresholds { warning: 70, critical: 85 };
+    assert_eq!(rising.color(&69), Color::Green);
+    assert_eq!(rising.color(&70), Color::Yellow);
+    assert_eq!(rising.color(&85), Color::Red);
+
+    let falling = Thresholds { warning: 20, critical: 5 };
+    assert_eq!(falling.color(&21), Color::Green);
+    assert_eq!(falling.color(&20), Color::Yellow);
+    assert_eq!(falling.color(&5), Color::Red);
+}
+
+#[test]
+#[cfg(feature = "i16")]
+fn colored_numbers() {
+    let mut buffer = [0u8; 16];
+    let i = numtoa_thresholds(-40i16, 10, &Thresholds { warning: 0, critical: -30 }, &mut buffer);
+    assert_eq!(&buffer[i..], b"\x1b[31m-40\x1b[0m");
+
+    let i = numtoa_color(0xFFi16, 16, Color::Cyan, &mut buffer);
+    assert_eq!(&buffer[i..], b"\x1b[36mFF\x1b[0m");
+}
@@ -0,0 +1,88 @@
+//! Rendering of values as bars of a fixed width, to accompany the numbers of console dashboards and histograms.
+//!
+//! ```
+//! use numtoa::bar::{format_bar, BarStyle};
+//!
+//! let mut buffer = [0u8; 40];
+//! let start = format_bar(3, 10, 10, BarStyle::Ascii, &mut buffer);
+//! assert_eq!(&buffer[start..], b"###.......");
+//!
+//! let start = format_bar(45, 100, 4, BarStyle::Blocks, &mut buffer);
+//! assert_eq!(&buffer[start..], "█▊  ".as_bytes());
+//! ```
+
+use super::rtl::Rtl;
+
+// The Unicode blocks which fill one to eight eighths of a cell.
+const EIGHTHS: [&str; 8] = ["▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+
+/// The characters which a bar is drawn with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BarStyle {
+    /// Draws the filled cells with `#` and the empty cells with `.`, such as `###.......`.
+    #[default]
+    Ascii,
+    /// Draws the filled cells with the Unicode block characters, with eighths of a cell, and the empty cells with
+    /// spaces. Each cell is written as three bytes of UTF-8 when filled, or one when empty.
+    Blocks,
+}
+
+/// Writes a bar of `width` cells into the end of the byte slice, filled in proportion to `value` out of `max`.
+/// The fill is rounded down, so that a bar is only full when the value reaches the maximum, and values above the
+/// maximum are drawn as a full bar. A maximum of zero draws an empty bar. Returns the indice where the inner
+/// string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the bar, this will panic.
+pub fn format_bar(value: u64, max: u64, width: usize, style: BarStyle, string: &mut [u8]) -> usize {
+    let (value, max) = (u128::from(value.min(max)), u128::from(max));
+    let eighths = (value * width as u128 * 8).checked_div(max).unwrap_or(0) as usize;
+
+    let mut rtl = Rtl::new(string);
+    match style {
+        BarStyle::Ascii => {
+            let filled = eighths / 8;
+            (filled..width).for_each(|_| rtl.byte(b'.'));
+            (0..filled).for_each(|_| rtl.byte(b'#'));
+        }
+        BarStyle::Blocks => {
+            let (filled, partial) = (eighths / 8, eighths % 8);
+            let used = filled + (partial != 0) as usize;
+            (used..width).for_each(|_| rtl.byte(b' '));
+            if partial != 0 {
+                rtl.bytes(EIGHTHS[partial - 1].as_bytes());
+            }
+            (0..filled).for_each(|_| rtl.bytes(EIGHTHS[7].as_bytes()));
+        }
+    }
+    rtl.index()
+}
+
+#[test]
+fn ascii_bars() {
+    let mut buffer = [0u8; 16];
+    let i = format_bar(0, 10, 5, BarStyle::Ascii, &mut buffer);
+    assert_eq!(&buffer[i..], b".....");
+
+    let i = format_bar(9, 10, 5, BarStyle::Ascii, &mut buffer);
+    assert_eq!(&buffer[i..], b"####.");
+
+    let i = format_bar(u64::MAX, 10, 5, BarStyle::Ascii, &mut buffer);
+    assert_eq!(&buffer[i..], b"#####");
+
+    let i = format_bar(1, 0, 3, BarStyle::Ascii, &mut buffer);
+    assert_eq!(&buffer[i..], b"...");
+}
+
+#[test]
+fn block_bars() {
+    let mut buffer = [0u8; 32];
+    let i = format_bar(1, 16, 2, BarStyle::Blocks, &mut buffer);
+    assert_eq!(&buffer[i..], "▏ ".as_bytes());
+
+    let i = format_bar(u64::MAX, u64::MAX, 3, BarStyle::Blocks, &mut buffer);
+    assert_eq!(&buffer[i..], "███".as_bytes());
+
+    let i = format_bar(0, 1, 0, BarStyle::Blocks, &mut buffer);
+    assert_eq!(&buffer[i..], b"");
+}
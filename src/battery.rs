@@ -0,0 +1,130 @@
+//! Presets for showing the state of charge of a battery as a percentage, from either its voltage or a coulomb
+//! counter. The percentage is rounded and clamped, so that a display never shows `101%` or `-1%`.
+//!
+//! ```
+//! use numtoa::battery::{format_soc_coulombs, format_soc_millivolts, LI_ION_CURVE};
+//!
+//! let mut buffer = [0u8; 8];
+//! let start = format_soc_millivolts(3_840, &LI_ION_CURVE, &mut buffer);
+//! assert_eq!(&buffer[start..], b"50%");
+//!
+//! let start = format_soc_coulombs(1_450, 1_000, &mut buffer);
+//! assert_eq!(&buffer[start..], b"100%");
+//! ```
+
+use super::fixed::Rounding;
+use super::rtl::Rtl;
+
+/// A typical resting discharge curve of a single lithium-ion cell, as pairs of millivolts and percentages.
+pub const LI_ION_CURVE: [(u32, u8); 21] = [
+    (3_270, 0),
+    (3_610, 5),
+    (3_690, 10),
+    (3_710, 15),
+    (3_730, 20),
+    (3_750, 25),
+    (3_770, 30),
+    (3_790, 35),
+    (3_800, 40),
+    (3_820, 45),
+    (3_840, 50),
+    (3_850, 55),
+    (3_870, 60),
+    (3_910, 65),
+    (3_950, 70),
+    (3_980, 75),
+    (4_020, 80),
+    (4_080, 85),
+    (4_110, 90),
+    (4_150, 95),
+    (4_200, 100),
+];
+
+/// Converts a cell voltage into a percentage by interpolating linearly between the points of a discharge curve,
+/// which are pairs of millivolts and percentages in ascending order. Voltages outside of the curve are clamped to
+/// its first and last points.
+///
+/// # Panics
+/// If the curve is empty, this will panic.
+pub fn soc_from_millivolts(millivolts: u32, curve: &[(u32, u8)]) -> u8 {
+    let (&(first_mv, first), &(last_mv, last)) = match (curve.first(), curve.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => panic!("numtoa: the discharge curve is empty"),
+    };
+    if millivolts <= first_mv {
+        return first.min(100);
+    } else if millivolts >= last_mv {
+        return last.min(100);
+    }
+
+    let upper = curve.iter().position(|&(mv, _)| mv > millivolts).unwrap_or(curve.len() - 1);
+    let ((low_mv, low), (high_mv, high)) = (curve[upper - 1], curve[upper]);
+    let (offset, span) = (u128::from(millivolts - low_mv), u128::from(high_mv - low_mv));
+    let percent = if high >= low {
+        u128::from(low) + Rounding::HalfUp.div(offset * u128::from(high - low), span)
+    } else {
+        u128::from(low) - Rounding::HalfUp.div(offset * u128::from(low - high), span)
+    };
+    percent.min(100) as u8
+}
+
+/// Converts the charge remaining according to a coulomb counter into a percentage of the capacity, rounded half
+/// up and clamped to 100, as counters commonly drift past the nominal capacity.
+///
+/// # Panics
+/// If the capacity is zero, this will panic.
+pub fn soc_from_coulombs(remaining: u64, capacity: u64) -> u8 {
+    assert!(capacity != 0, "numtoa: the battery capacity is zero");
+    Rounding::HalfUp.div(u128::from(remaining) * 100, u128::from(capacity)).min(100) as u8
+}
+
+fn format_percent(percent: u8, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    rtl.byte(b'%');
+    rtl.digits(u128::from(percent));
+    rtl.index()
+}
+
+/// Writes the state of charge of a cell at the given voltage into the end of the byte slice, such as `87%`, using
+/// `soc_from_millivolts`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the curve is empty, or the supplied buffer is too small to hold the output, this will panic.
+pub fn format_soc_millivolts(millivolts: u32, curve: &[(u32, u8)], string: &mut [u8]) -> usize {
+    format_percent(soc_from_millivolts(millivolts, curve), string)
+}
+
+/// Writes the state of charge according to a coulomb counter into the end of the byte slice, such as `87%`, using
+/// `soc_from_coulombs`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the capacity is zero, or the supplied buffer is too small to hold the output, this will panic.
+pub fn format_soc_coulombs(remaining: u64, capacity: u64, string: &mut [u8]) -> usize {
+    format_percent(soc_from_coulombs(remaining, capacity), string)
+}
+
+#[test]
+fn soc_from_voltage() {
+    assert_eq!(soc_from_millivolts(0, &LI_ION_CURVE), 0);
+    assert_eq!(soc_from_millivolts(3_270, &LI_ION_CURVE), 0);
+    assert_eq!(soc_from_millivolts(3_440, &LI_ION_CURVE), 3);
+    assert_eq!(soc_from_millivolts(4_199, &LI_ION_CURVE), 100);
+    assert_eq!(soc_from_millivolts(5_000, &LI_ION_CURVE), 100);
+
+    // A curve which runs downwards, and one which claims more than a full charge.
+    assert_eq!(soc_from_millivolts(150, &[(100, 80), (200, 20)]), 50);
+    assert_eq!(soc_from_millivolts(300, &[(100, 0), (200, 250)]), 100);
+}
+
+#[test]
+fn soc_from_counter() {
+    let mut buffer = [0u8; 4];
+    let i = format_soc_coulombs(0, 3_000, &mut buffer);
+    assert_eq!(&buffer[i..], b"0%");
+
+    let i = format_soc_coulombs(2_985, 3_000, &mut buffer);
+    assert_eq!(&buffer[i..], b"100%");
+
+    let i = format_soc_coulombs(2_984, 3_000, &mut buffer);
+    assert_eq!(&buffer[i..], b"99%");
+}
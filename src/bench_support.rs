@@ -0,0 +1,161 @@
+//! Ready-made criterion benchmarks, so that integrators can measure the crate on their own targets, and so that
+//! regressions in the conversions show up as soon as they land. The base 10 benchmarks run the same values through
+//! `itoa` and `core::fmt` alongside the crate, as points of reference.
+//!
+//! Each function registers its benchmarks with a `Criterion`, so a bench target only needs to group them:
+//!
+//! ```ignore
+//! use criterion::{criterion_group, criterion_main};
+//!
+//! criterion_group!(benches, numtoa::bench_support::all);
+//! criterion_main!(benches);
+//! ```
+
+use core::fmt::{self, Write};
+use core::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion};
+
+use super::fixed::{format_scaled, Rounding};
+use super::human::format_compact;
+use super::rtl::SCRATCH_LEN;
+use super::template::{patch_field, Align};
+use super::{write_i128_base10, write_i16_base10, write_i32_base10, write_i64_base10, write_i8_base10};
+use super::{write_u128_base10, write_u16_base10, write_u32_base10, write_u64_base10, write_u8_base10, NumToA};
+
+// The bases which every integer type is measured in.
+const BASES: [u32; 5] = [2, 8, 10, 16, 36];
+
+// Writes formatted text into a byte slice, so that `core::fmt` is measured without allocating.
+struct SliceWriter<'a> {
+    string: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.string.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+macro_rules! bench_types {
+    ($($t:ident, $write_base10:ident;)*) => {
+        /// Measures `NumToA::numtoa` for every integer type from `u8` to `i128`, in bases 2, 8, 10, 16, and 36,
+        /// over a spread of values from the shortest to the longest.
+        pub fn bases(c: &mut Criterion) {
+            $(
+                let samples: [$t; 5] = [0, 9, <$t>::MAX / 3, <$t>::MIN, <$t>::MAX];
+                let mut group = c.benchmark_group(concat!("numtoa/", stringify!($t)));
+                for &base in &BASES {
+                    group.bench_with_input(BenchmarkId::from_parameter(base), &(base as $t), |b, &base| {
+                        let mut buffer = [0u8; SCRATCH_LEN];
+                        b.iter(|| {
+                            for &number in &samples {
+                                black_box(black_box(number).numtoa(base, &mut buffer));
+                            }
+                        })
+                    });
+                }
+                group.finish();
+            )*
+        }
+
+        /// Measures base 10 conversions of every integer type from `u8` to `i128` through the crate's
+        /// `write_*_base10` fast paths, against `itoa` and `core::fmt` on the same values.
+        pub fn base10(c: &mut Criterion) {
+            $(
+                let samples: [$t; 5] = [0, 9, <$t>::MAX / 3, <$t>::MIN, <$t>::MAX];
+                let mut group = c.benchmark_group(concat!("base10/", stringify!($t)));
+                group.bench_function("numtoa", |b| {
+                    let mut buffer = [0u8; 40];
+                    b.iter(|| {
+                        for &number in &samples {
+                            black_box($write_base10(black_box(number), &mut buffer));
+                        }
+                    })
+                });
+                group.bench_function("itoa", |b| {
+                    let mut buffer = itoa::Buffer::new();
+                    b.iter(|| {
+                        for &number in &samples {
+                            black_box(buffer.format(black_box(number)).len());
+                        }
+                    })
+                });
+                group.bench_function("core::fmt", |b| {
+                    let mut buffer = [0u8; 40];
+                    b.iter(|| {
+                        for &number in &samples {
+                            let mut writer = SliceWriter { string: &mut buffer, len: 0 };
+                            let _ = write!(writer, "{}", black_box(number));
+                            black_box(writer.len);
+                        }
+                    })
+                });
+                group.finish();
+            )*
+        }
+    };
+}
+
+bench_types! {
+    u8, write_u8_base10;
+    i8, write_i8_base10;
+    u16, write_u16_base10;
+    i16, write_i16_base10;
+    u32, write_u32_base10;
+    i32, write_i32_base10;
+    u64, write_u64_base10;
+    i64, write_i64_base10;
+    u128, write_u128_base10;
+    i128, write_i128_base10;
+}
+
+/// Measures the formatters which take options: a padded field in a template aligned to either side, fixed point
+/// in every rounding mode, and compact counts.
+pub fn options(c: &mut Criterion) {
+    let mut group = c.benchmark_group("options");
+    for &(name, align) in &[("patch_field/left", Align::Left), ("patch_field/right", Align::Right)] {
+        group.bench_function(name, |b| {
+            let mut template = *b"temperature:      C";
+            b.iter(|| patch_field(&mut template, 12, 6, black_box(-2150i32), 10, align, b' '))
+        });
+    }
+
+    let roundings = [
+        ("truncate",  Rounding::Truncate),
+        ("half_up",   Rounding::HalfUp),
+        ("half_even", Rounding::HalfEven),
+        ("up",        Rounding::Up),
+    ];
+    for &(name, rounding) in &roundings {
+        group.bench_with_input(BenchmarkId::new("format_scaled", name), &rounding, |b, &rounding| {
+            let mut buffer = [0u8; 32];
+            b.iter(|| black_box(format_scaled(black_box(-1_234_567), 4, 2, rounding, &mut buffer)))
+        });
+        group.bench_with_input(BenchmarkId::new("format_compact", name), &rounding, |b, &rounding| {
+            let mut buffer = [0u8; 16];
+            b.iter(|| black_box(format_compact(black_box(1_234_567), rounding, &mut buffer)))
+        });
+    }
+    group.finish();
+}
+
+/// Registers every benchmark of this module.
+pub fn all(c: &mut Criterion) {
+    bases(c);
+    base10(c);
+    options(c);
+}
+
+#[test]
+fn slice_writer() {
+    let mut buffer = [0u8; 8];
+    let mut writer = SliceWriter { string: &mut buffer, len: 0 };
+    assert!(write!(writer, "{}", -1234i16).is_ok());
+    assert!(write!(writer, "{}", 5678u16).is_err());
+    assert_eq!(&buffer[..5], b"-1234");
+}
@@ -0,0 +1,71 @@
+//! Conversions of `num-bigint`'s `BigUint` and `BigInt` types, which are considerably faster than
+//! `to_str_radix`, and only allocate once for a scratch copy of the limbs.
+//!
+//! ```
+//! extern crate num_bigint;
+//! # extern crate numtoa;
+//! use num_bigint::BigUint;
+//! use numtoa::NumToA;
+//! use numtoa::bigint::biguint_to_vec;
+//!
+//! let value = BigUint::from(u64::max_value()) * 1000u32;
+//! let mut buffer = [0u8; 32];
+//! let start = (&value).numtoa(10, &mut buffer);
+//! assert_eq!(&buffer[start..], b"18446744073709551615000");
+//! assert_eq!(biguint_to_vec(&value, 16), b"3E7FFFFFFFFFFFFFC18".to_vec());
+//! ```
+
+use alloc::vec::Vec;
+use num_bigint::{BigInt, BigUint, Sign};
+
+use super::limbs::limbs_numtoa;
+use super::NumToA;
+
+impl NumToA<u32> for &BigUint {
+    fn numtoa(self, base: u32, string: &mut [u8]) -> usize {
+        limbs_numtoa(&mut self.to_u64_digits(), false, base, string)
+    }
+}
+
+impl NumToA<u32> for &BigInt {
+    fn numtoa(self, base: u32, string: &mut [u8]) -> usize {
+        let (sign, mut limbs) = self.to_u64_digits();
+        limbs_numtoa(&mut limbs, sign == Sign::Minus, base, string)
+    }
+}
+
+// The maximum number of digits needed to represent a number with the given bits in the given base.
+fn max_digits(bits: u64, base: u32) -> usize {
+    assert!((2..=36).contains(&base), "base must be within 2 and 36");
+    let bits_per_digit = u64::from(31 - base.leading_zeros());
+    (bits / bits_per_digit) as usize + 1
+}
+
+// Converts within a buffer of the maximum size, and then moves the digits to the front of it.
+fn numtoa_vec<T: NumToA<u32>>(value: T, bits: u64, base: u32) -> Vec<u8> {
+    let mut vec = alloc::vec![0u8; max_digits(bits, base) + 1];
+    let start = value.numtoa(base, &mut vec);
+    vec.drain(..start);
+    vec
+}
+
+/// Converts a `BigUint` into a newly-allocated vector of digits in the given base.
+pub fn biguint_to_vec(value: &BigUint, base: u32) -> Vec<u8> {
+    numtoa_vec(value, value.bits(), base)
+}
+
+/// Converts a `BigInt` into a newly-allocated vector of digits in the given base, with a leading `-` if negative.
+pub fn bigint_to_vec(value: &BigInt, base: u32) -> Vec<u8> {
+    numtoa_vec(value, value.bits(), base)
+}
+
+#[test]
+fn bigint_conversions() {
+    let value = BigInt::from(i64::MIN) * BigInt::from(i64::MAX);
+    assert_eq!(bigint_to_vec(&value, 10), value.to_str_radix(10).into_bytes());
+    assert_eq!(bigint_to_vec(&value, 2), value.to_str_radix(2).into_bytes());
+    assert_eq!(bigint_to_vec(&BigInt::from(0), 7), b"0".to_vec());
+
+    let value = BigUint::from(35u32).pow(100);
+    assert_eq!(biguint_to_vec(&value, 36), value.to_str_radix(36).to_uppercase().into_bytes());
+}
@@ -0,0 +1,65 @@
+//! Output of numbers as Unicode Braille patterns, for accessibility devices which show them on a refreshable
+//! display. Digits are written as the letters `a` through `j` after the numeric indicator `⠼`, as in Unified
+//! English Braille, with each cell taking three bytes of UTF-8.
+//!
+//! ```
+//! use numtoa::braille::format_braille;
+//!
+//! let mut buffer = [0u8; 32];
+//! let start = format_braille(-2024, &mut buffer);
+//! assert_eq!(&buffer[start..], "⠐⠤⠼⠃⠚⠃⠙".as_bytes());
+//! ```
+
+use super::rtl::Rtl;
+
+// The first of the Unicode Braille patterns, to which the raised dots are added as bits.
+const PATTERNS: u32 = 0x2800;
+
+const NUMERIC_INDICATOR: u8 = 0b0011_1100;
+const MINUS: [u8; 2] = [0b0001_0000, 0b0010_0100];
+
+// The dots of the digits 0 through 9, which are those of the letters `j` and `a` through `i`.
+const DIGITS: [u8; 10] = [0x1A, 0x01, 0x03, 0x09, 0x19, 0x11, 0x0B, 0x1B, 0x13, 0x0A];
+
+fn cell(dots: u8) -> char {
+    char::from_u32(PATTERNS + u32::from(dots)).unwrap()
+}
+
+/// Writes the decimal digits of the number into the end of the byte slice as Braille patterns in UTF-8, after
+/// the numeric indicator, with a leading minus written as `⠐⠤`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, which takes three bytes for every cell, this will panic.
+pub fn format_braille(value: i128, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    let mut rest = value.unsigned_abs();
+    loop {
+        rtl.char(cell(DIGITS[(rest % 10) as usize]));
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+
+    rtl.char(cell(NUMERIC_INDICATOR));
+    if value < 0 {
+        MINUS.iter().rev().for_each(|&dots| rtl.char(cell(dots)));
+    }
+    rtl.index()
+}
+
+#[test]
+fn braille_digits() {
+    let mut buffer = [0u8; 64];
+    let i = format_braille(1_234_567_890, &mut buffer);
+    assert_eq!(&buffer[i..], "⠼⠁⠃⠉⠙⠑⠋⠛⠓⠊⠚".as_bytes());
+
+    let i = format_braille(0, &mut buffer);
+    assert_eq!(&buffer[i..], "⠼⠚".as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn braille_too_small() {
+    format_braille(10, &mut [0u8; 8]);
+}
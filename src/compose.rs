@@ -0,0 +1,85 @@
+//! Composition of several right-aligned fields into one buffer in a single right-to-left pass, as display drivers
+//! lay out a row of readings. Each field reserves exactly its width, whatever its value, so the layout never shifts.
+//!
+//! ```
+//! # #[cfg(all(feature = "u8", feature = "i16"))] {
+//! use numtoa::compose::Composer;
+//! use numtoa::template::Align;
+//!
+//! let mut buffer = [0u8; 32];
+//! let mut row = Composer::new(&mut buffer);
+//! row.field(-40i16, 10, 5, Align::Right, b' ').bytes(b"C ").field(7u8, 10, 3, Align::Right, b'0').bytes(b"#");
+//! let start = row.index();
+//! assert_eq!(&buffer[start..], b"#007C   -40");
+//! # }
+//! ```
+//!
+//! As every conversion of this crate does, the fields are written from the end of the buffer towards its start,
+//! so the rightmost field is added first.
+
+use super::rtl::{Rtl, SCRATCH_LEN};
+use super::template::{fill_field, Align};
+use super::NumToA;
+
+/// A writer which adds fields to a buffer from right to left, each to the left of the last.
+pub struct Composer<'a> {
+    rtl: Rtl<'a>,
+}
+
+impl<'a> Composer<'a> {
+    /// Begins composing at the end of the buffer.
+    pub fn new(string: &'a mut [u8]) -> Self {
+        Composer { rtl: Rtl::new(string) }
+    }
+
+    /// The indice where the composed string begins.
+    pub fn index(&self) -> usize {
+        self.rtl.index()
+    }
+
+    /// Reserves the next `width` bytes to the left and fills them with the number in the given base, aligned to
+    /// one side of the field and padded with `pad` on the other.
+    ///
+    /// # Panics
+    /// If the number has more digits than the width, or the buffer is too small to reserve the field, this will
+    /// panic, as do the other fields.
+    pub fn field<T: NumToA<B>, B>(&mut self, number: T, base: B, width: usize, align: Align, pad: u8) -> &mut Self {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let start = number.numtoa(base, &mut scratch);
+        let field = self.rtl.reserve(width);
+        fill_field(field, &scratch[start..], align, pad);
+        self
+    }
+
+    /// Adds the number in the given base to the left, taking only as many bytes as it needs.
+    pub fn number<T: NumToA<B>, B>(&mut self, number: T, base: B) -> &mut Self {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let start = number.numtoa(base, &mut scratch);
+        self.rtl.bytes(&scratch[start..]);
+        self
+    }
+
+    /// Adds literal bytes to the left, such as a unit or a separator.
+    pub fn bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.rtl.bytes(bytes);
+        self
+    }
+}
+
+#[test]
+#[cfg(feature = "u32")]
+fn composed_fields() {
+    let mut buffer = [0u8; 16];
+    let mut row = Composer::new(&mut buffer);
+    row.field(5u32, 10, 4, Align::Left, b'.').bytes(b"|").number(0xABu32, 16);
+    let i = row.index();
+    assert_eq!(&buffer[i..], b"AB|5...");
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "u32")]
+fn composed_field_too_wide() {
+    let mut buffer = [0u8; 16];
+    Composer::new(&mut buffer).field(12345u32, 10, 4, Align::Right, b' ');
+}
@@ -0,0 +1,91 @@
+//! Formatting of CRCs and checksums at exactly the hexadecimal width of their algorithm, so that the columns of
+//! protocol traces line up whatever the value.
+//!
+//! ```
+//! use numtoa::crc::format_crc;
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_crc(0x31C3, 16, true, &mut buffer);
+//! assert_eq!(&buffer[start..], b"0x31C3");
+//!
+//! let start = format_crc(0x0A, 8, false, &mut buffer);
+//! assert_eq!(&buffer[start..], b"0A");
+//! ```
+
+use super::rtl::Rtl;
+
+/// Writes a checksum of `bits` bits into the end of the byte slice as upper case hexadecimal, with as many digits
+/// as the widest checksum of the algorithm needs, such as four for CRC-16 and three for CRC-12, optionally
+/// prefixed with `0x`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If `bits` is not between 1 and 64, if the value does not fit in `bits` bits, or if the supplied buffer is too
+/// small to hold the output, this will panic.
+pub fn format_crc(value: u64, bits: u32, prefix: bool, string: &mut [u8]) -> usize {
+    assert!((1..=64).contains(&bits), "numtoa: checksums are 1 to 64 bits wide");
+    assert!(bits == 64 || value >> bits == 0, "numtoa: checksum is wider than its algorithm");
+
+    let mut rtl = Rtl::new(string);
+    rtl.hex_padded(u128::from(value), bits.div_ceil(4) as usize);
+    if prefix {
+        rtl.bytes(b"0x");
+    }
+    rtl.index()
+}
+
+/// Writes a CRC-8 as two hexadecimal digits, optionally prefixed with `0x`.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_crc8(crc: u8, prefix: bool, string: &mut [u8]) -> usize {
+    format_crc(u64::from(crc), 8, prefix, string)
+}
+
+/// Writes a CRC-16 as four hexadecimal digits, optionally prefixed with `0x`.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_crc16(crc: u16, prefix: bool, string: &mut [u8]) -> usize {
+    format_crc(u64::from(crc), 16, prefix, string)
+}
+
+/// Writes a CRC-32 as eight hexadecimal digits, optionally prefixed with `0x`.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_crc32(crc: u32, prefix: bool, string: &mut [u8]) -> usize {
+    format_crc(u64::from(crc), 32, prefix, string)
+}
+
+/// Writes a CRC-64 as sixteen hexadecimal digits, optionally prefixed with `0x`.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_crc64(crc: u64, prefix: bool, string: &mut [u8]) -> usize {
+    format_crc(crc, 64, prefix, string)
+}
+
+#[test]
+fn crc_widths() {
+    let mut buffer = [0u8; 24];
+    let i = format_crc8(0, true, &mut buffer);
+    assert_eq!(&buffer[i..], b"0x00");
+
+    let i = format_crc32(0xCBF4_3926, false, &mut buffer);
+    assert_eq!(&buffer[i..], b"CBF43926");
+
+    let i = format_crc64(1, true, &mut buffer);
+    assert_eq!(&buffer[i..], b"0x0000000000000001");
+
+    let i = format_crc(0x1E5, 12, false, &mut buffer);
+    assert_eq!(&buffer[i..], b"1E5");
+
+    let i = format_crc(0x5, 5, false, &mut buffer);
+    assert_eq!(&buffer[i..], b"05");
+}
+
+#[test]
+#[should_panic]
+fn crc_too_wide() {
+    format_crc(0x1_0000, 16, false, &mut [0u8; 24]);
+}
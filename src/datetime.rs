@@ -0,0 +1,538 @@
+//! Calendar dates and times of day from Unix timestamps, in the proleptic Gregorian calendar and UTC, along with
+//! the short English names of the weekdays and months that HTTP and mail headers are written with.
+//!
+//! ```
+//! use numtoa::datetime::{format_date, DateTime};
+//!
+//! let time = DateTime::from_unix(1_715_085_296);
+//! assert_eq!((time.year, time.month, time.day), (2024, 5, 7));
+//! assert_eq!(time.weekday_name(), "Tue");
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_date(1_715_085_296, &mut buffer);
+//! assert_eq!(&buffer[start..], b"Tue, 07 May 2024");
+//! ```
+//!
+//! Timestamps are exchanged with servers as RFC 3339 strings, which are written and parsed as well.
+//!
+//! ```
+//! use numtoa::datetime::{format_rfc3339, parse_rfc3339};
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_rfc3339(1_714_566_896, &mut buffer);
+//! assert_eq!(&buffer[start..], b"2024-05-01T12:34:56Z");
+//!
+//! assert_eq!(parse_rfc3339(b"2024-05-01T14:34:56.25+02:00"), Ok((1_714_566_896, 250_000_000)));
+//! ```
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use super::rtl::Rtl;
+
+/// The short English names of the weekdays, beginning with Monday.
+pub const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// The short English names of the months, beginning with January.
+pub const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// A date and time of day in UTC. The default is the Unix epoch, midnight on the 1st of January 1970.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    /// The year, where the year before 1 AD is 0.
+    pub year: i64,
+    /// The month, from 1 for January to 12 for December.
+    pub month: u8,
+    /// The day of the month, from 1.
+    pub day: u8,
+    /// The hour, from 0 to 23.
+    pub hour: u8,
+    /// The minute, from 0 to 59.
+    pub minute: u8,
+    /// The second, from 0 to 59.
+    pub second: u8,
+    /// The day of the week, from 0 for Monday to 6 for Sunday.
+    pub weekday: u8,
+}
+
+impl DateTime {
+    /// Converts a count of seconds since the Unix epoch, ignoring leap seconds as Unix time does.
+    pub fn from_unix(secs: i64) -> Self {
+        let (days, seconds) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+        // The days are counted from the 1st of March of the year 0, so that leap days fall at the end of each
+        // year, and grouped into eras of 400 years which each hold the same number of days.
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+
+        DateTime {
+            year: era * 400 + year_of_era + (month <= 2) as i64,
+            month: month as u8,
+            day: day as u8,
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+            // The epoch fell on a Thursday.
+            weekday: ((days - 719_468).rem_euclid(7) + 3).rem_euclid(7) as u8,
+        }
+    }
+
+    /// Converts a count of seconds since the Unix epoch, as `from_unix` does, but only when its year is within the
+    /// accepted range.
+    ///
+    /// # Errors
+    /// Returns `OutOfRange` when the year of the timestamp is outside of the range.
+    pub fn try_from_unix(secs: i64, years: YearRange) -> Result<Self, OutOfRange> {
+        let time = DateTime::from_unix(secs);
+        if years.contains(time.year) { Ok(time) } else { Err(OutOfRange) }
+    }
+
+    /// Converts the date and time back into a count of seconds since the Unix epoch. The weekday is ignored, and
+    /// the other fields are not checked, so a month, day, or time of day beyond its range carries over into the
+    /// next one. `try_to_unix` rejects them instead.
+    ///
+    /// # Panics
+    /// If the year is so far from the epoch that the timestamp does not fit in an `i64`, this will panic.
+    pub fn to_unix(&self) -> i64 {
+        i64::try_from(self.seconds()).expect("numtoa: timestamp overflows an i64")
+    }
+
+    /// Converts the date and time back into a count of seconds since the Unix epoch, as `to_unix` does, but only
+    /// when every field is within its range and the year is within the accepted range. The weekday is ignored.
+    ///
+    /// # Errors
+    /// Returns `OutOfRange` when the month or day does not exist, the hour, minute, or second is out of range,
+    /// the year is outside of the range, or the timestamp does not fit in an `i64`.
+    pub fn try_to_unix(&self, years: YearRange) -> Result<i64, OutOfRange> {
+        let valid = years.contains(self.year)
+            && (1..=12).contains(&self.month)
+            && (1..=days_in_month(self.year, self.month)).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second < 60;
+        if !valid {
+            return Err(OutOfRange);
+        }
+        i64::try_from(self.seconds()).map_err(|_| OutOfRange)
+    }
+
+    // The seconds since the Unix epoch, computed in 128 bits so that no year overflows.
+    fn seconds(&self) -> i128 {
+        let (month, day) = (i128::from(self.month), i128::from(self.day));
+        let year = i128::from(self.year) - (month <= 2) as i128;
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146_097 + day_of_era - 719_468;
+        days * 86_400 + i128::from(self.hour) * 3600 + i128::from(self.minute) * 60 + i128::from(self.second)
+    }
+
+    /// The short English name of the weekday, such as `Tue`.
+    ///
+    /// # Panics
+    /// If the weekday was set by hand to a value above 6, this will panic.
+    pub fn weekday_name(&self) -> &'static str {
+        WEEKDAYS[usize::from(self.weekday)]
+    }
+
+    /// The short English name of the month, such as `May`.
+    ///
+    /// # Panics
+    /// If the month was set by hand to a value outside of 1 through 12, this will panic.
+    pub fn month_name(&self) -> &'static str {
+        MONTHS[usize::from(self.month).wrapping_sub(1)]
+    }
+}
+
+impl Default for DateTime {
+    fn default() -> Self {
+        DateTime { year: 1970, month: 1, day: 1, hour: 0, minute: 0, second: 0, weekday: 3 }
+    }
+}
+
+/// The years which the checked conversions accept.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum YearRange {
+    /// Every year which a Unix timestamp can reach, in the proleptic Gregorian calendar, including the years
+    /// before 1 AD and those with more than four digits.
+    Proleptic,
+    /// Only the years 1970 through 9999, which fit the four digit years of RFC 3339 and HTTP-dates without
+    /// reaching before the Unix epoch.
+    Unix,
+    /// Only the years 0000 through 9999, which are every year that the four digit years of RFC 3339 and
+    /// HTTP-dates can hold.
+    #[default]
+    FourDigit,
+}
+
+impl YearRange {
+    fn contains(self, year: i64) -> bool {
+        match self {
+            YearRange::Proleptic => true,
+            YearRange::Unix      => (1970..=9999).contains(&year),
+            YearRange::FourDigit => (0..=9999).contains(&year),
+        }
+    }
+}
+
+/// How a leap second, written with a second of `60`, is parsed. Unix time has no leap seconds to represent
+/// them with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LeapSecond {
+    /// Rejects a second of `60` as an invalid time.
+    #[default]
+    Reject,
+    /// Parses a second of `60` as the last nanosecond of the second before it, which keeps timestamps in order.
+    Clamp,
+    /// Parses a second of `60` as the first second of the following minute.
+    Roll,
+}
+
+/// The options of the checked conversions between timestamps and dates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Policy {
+    /// The years which are accepted.
+    pub years: YearRange,
+    /// How leap seconds are parsed.
+    pub leap_second: LeapSecond,
+}
+
+/// The error of a conversion whose timestamp lies outside of the accepted years, or whose date or time of day
+/// does not exist.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfRange;
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("timestamp is outside of the accepted years or does not exist")
+    }
+}
+
+/// The reason that a string could not be parsed as an RFC 3339 timestamp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseTimestampError {
+    /// The input does not have the layout of `YYYY-MM-DDTHH:MM:SS`, with optional fractional seconds, followed by
+    /// `Z` or an offset of `+HH:MM` or `-HH:MM`.
+    Malformed,
+    /// The month or the day of the month does not exist, such as `2023-02-29`.
+    InvalidDate,
+    /// The hour, minute, or second is out of range, such as `24:00:00`.
+    InvalidTime,
+    /// The offset from UTC is out of range, such as `+24:00`.
+    InvalidOffset,
+    /// The timestamp lies outside of the years accepted by the policy.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseTimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ParseTimestampError::Malformed     => "timestamp is not in the format of RFC 3339",
+            ParseTimestampError::InvalidDate   => "date does not exist",
+            ParseTimestampError::InvalidTime   => "time of day is out of range",
+            ParseTimestampError::InvalidOffset => "offset from UTC is out of range",
+            ParseTimestampError::OutOfRange    => "timestamp is outside of the accepted years",
+        })
+    }
+}
+
+// The number of days within the month of the year.
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Parses a run of decimal digits of a known length.
+fn parse_digits(digits: &[u8]) -> Result<u32, ParseTimestampError> {
+    digits.iter().try_fold(0u32, |value, &byte| match byte {
+        b'0'..=b'9' => Ok(value * 10 + u32::from(byte - b'0')),
+        _ => Err(ParseTimestampError::Malformed),
+    })
+}
+
+/// Parses an RFC 3339 timestamp, such as `2024-05-01T12:34:56Z` or `2024-05-01T14:34:56.25+02:00`, into seconds
+/// since the Unix epoch and nanoseconds within the second. The `T` may also be written as `t` or a space, and the
+/// `Z` as `z`. Fractional digits beyond nanoseconds are accepted and truncated.
+///
+/// This uses the default policy, which accepts the years 0000 through 9999 and rejects leap seconds.
+///
+/// # Errors
+/// Returns the reason that the timestamp was rejected, without wrapping any out of range field around.
+pub fn parse_rfc3339(string: &[u8]) -> Result<(i64, u32), ParseTimestampError> {
+    parse_rfc3339_with(string, Policy::default())
+}
+
+/// Parses an RFC 3339 timestamp as `parse_rfc3339` does, with the years and leap seconds accepted according to
+/// the policy. The year range applies to the timestamp in UTC.
+///
+/// # Errors
+/// Returns the reason that the timestamp was rejected, without wrapping any out of range field around.
+///
+/// # Example
+/// ```
+/// use numtoa::datetime::{parse_rfc3339_with, LeapSecond, ParseTimestampError, Policy, YearRange};
+///
+/// let policy = Policy { years: YearRange::Unix, leap_second: LeapSecond::Clamp };
+/// assert_eq!(parse_rfc3339_with(b"2016-12-31T23:59:60Z", policy), Ok((1_483_228_799, 999_999_999)));
+/// assert_eq!(parse_rfc3339_with(b"1969-12-31T23:59:59Z", policy), Err(ParseTimestampError::OutOfRange));
+/// ```
+pub fn parse_rfc3339_with(string: &[u8], policy: Policy) -> Result<(i64, u32), ParseTimestampError> {
+    if string.len() < 20
+        || (string[4], string[7], string[13], string[16]) != (b'-', b'-', b':', b':')
+        || !matches!(string[10], b'T' | b't' | b' ')
+    {
+        return Err(ParseTimestampError::Malformed);
+    }
+
+    let time = DateTime {
+        year: i64::from(parse_digits(&string[0..4])?),
+        month: parse_digits(&string[5..7])? as u8,
+        day: parse_digits(&string[8..10])? as u8,
+        hour: parse_digits(&string[11..13])? as u8,
+        minute: parse_digits(&string[14..16])? as u8,
+        second: parse_digits(&string[17..19])? as u8,
+        weekday: 0,
+    };
+
+    let mut rest = &string[19..];
+    let mut nanos = 0;
+    if rest[0] == b'.' {
+        let digits = rest[1..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+        if digits == 0 {
+            return Err(ParseTimestampError::Malformed);
+        }
+        let kept = digits.min(9);
+        nanos = parse_digits(&rest[1..1 + kept])? * 10u32.pow(9 - kept as u32);
+        rest = &rest[1 + digits..];
+    }
+
+    let offset = match rest {
+        [b'Z'] | [b'z'] => 0,
+        [sign @ (b'+' | b'-'), hours @ .., b':', m1, m2] if hours.len() == 2 => {
+            let (hours, minutes) = (parse_digits(hours)?, parse_digits(&[*m1, *m2])?);
+            if hours > 23 || minutes > 59 {
+                return Err(ParseTimestampError::InvalidOffset);
+            }
+            let offset = i64::from(hours * 3600 + minutes * 60);
+            if *sign == b'-' { -offset } else { offset }
+        }
+        _ => return Err(ParseTimestampError::Malformed),
+    };
+
+    if time.month == 0 || time.month > 12 || time.day == 0 || time.day > days_in_month(time.year, time.month) {
+        return Err(ParseTimestampError::InvalidDate);
+    } else if time.hour > 23 || time.minute > 59 || time.second > 60 {
+        return Err(ParseTimestampError::InvalidTime);
+    }
+
+    let mut secs = time.to_unix() - offset;
+    if time.second == 60 {
+        match policy.leap_second {
+            LeapSecond::Reject => return Err(ParseTimestampError::InvalidTime),
+            LeapSecond::Clamp => {
+                secs -= 1;
+                nanos = 999_999_999;
+            }
+            LeapSecond::Roll => (),
+        }
+    }
+
+    if !policy.years.contains(DateTime::from_unix(secs).year) {
+        return Err(ParseTimestampError::OutOfRange);
+    }
+    Ok((secs, nanos))
+}
+
+// Writes the year with at least four digits, and a `-` before the years before 0.
+pub(crate) fn write_year(rtl: &mut Rtl, year: i64) {
+    rtl.digits_padded(u128::from(year.unsigned_abs()), 4, b'0');
+    if year < 0 {
+        rtl.byte(b'-');
+    }
+}
+
+// Writes the time of day as `HH:MM:SS`.
+pub(crate) fn write_time(rtl: &mut Rtl, time: &DateTime) {
+    rtl.digits_padded(u128::from(time.second), 2, b'0');
+    rtl.byte(b':');
+    rtl.digits_padded(u128::from(time.minute), 2, b'0');
+    rtl.byte(b':');
+    rtl.digits_padded(u128::from(time.hour), 2, b'0');
+}
+
+// Writes the date with the names of its weekday and month, such as `Tue, 07 May 2024`.
+pub(crate) fn write_named_date(rtl: &mut Rtl, time: &DateTime) {
+    write_year(rtl, time.year);
+    rtl.byte(b' ');
+    rtl.bytes(time.month_name().as_bytes());
+    rtl.byte(b' ');
+    rtl.digits_padded(u128::from(time.day), 2, b'0');
+    rtl.bytes(b", ");
+    rtl.bytes(time.weekday_name().as_bytes());
+}
+
+/// Writes the Unix timestamp into the end of the byte slice as an RFC 3339 timestamp in UTC, such as
+/// `2024-05-01T12:34:56Z`. Returns the indice where the inner string begins.
+///
+/// Every timestamp is written, including those whose years fall outside of 0000 through 9999, which are written
+/// with a sign or more than four digits that RFC 3339 does not allow. Use `try_format_rfc3339` with
+/// `YearRange::FourDigit` to reject them instead.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, which is 20 bytes for the years 0 through 9999, this
+/// will panic.
+pub fn format_rfc3339(secs: i64, string: &mut [u8]) -> usize {
+    try_format_rfc3339(secs, YearRange::Proleptic, string).expect("numtoa: every year is accepted")
+}
+
+/// Writes the Unix timestamp as `format_rfc3339` does, but only when its year is within the accepted range.
+///
+/// # Errors
+/// Returns `OutOfRange` when the year of the timestamp is outside of the range, without writing anything.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn try_format_rfc3339(secs: i64, years: YearRange, string: &mut [u8]) -> Result<usize, OutOfRange> {
+    let time = DateTime::try_from_unix(secs, years)?;
+    let mut rtl = Rtl::new(string);
+    rtl.byte(b'Z');
+    write_time(&mut rtl, &time);
+    rtl.byte(b'T');
+    rtl.digits_padded(u128::from(time.day), 2, b'0');
+    rtl.byte(b'-');
+    rtl.digits_padded(u128::from(time.month), 2, b'0');
+    rtl.byte(b'-');
+    write_year(&mut rtl, time.year);
+    Ok(rtl.index())
+}
+
+/// Writes the date of the Unix timestamp into the end of the byte slice with the short English names of its
+/// weekday and month, such as `Tue, 07 May 2024`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_date(secs: i64, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    write_named_date(&mut rtl, &DateTime::from_unix(secs));
+    rtl.index()
+}
+
+#[test]
+fn unix_to_civil() {
+    let epoch = DateTime::from_unix(0);
+    assert_eq!(epoch, DateTime { year: 1970, month: 1, day: 1, hour: 0, minute: 0, second: 0, weekday: 3 });
+
+    let leap = DateTime::from_unix(951_825_599);
+    assert_eq!(leap, DateTime { year: 2000, month: 2, day: 29, hour: 11, minute: 59, second: 59, weekday: 1 });
+
+    let before = DateTime::from_unix(-1);
+    assert_eq!(before, DateTime { year: 1969, month: 12, day: 31, hour: 23, minute: 59, second: 59, weekday: 2 });
+
+    // Both extremes convert without overflowing.
+    assert_eq!(DateTime::from_unix(i64::MAX).year, 292_277_026_596);
+    assert_eq!(DateTime::from_unix(i64::MIN).year, -292_277_022_657);
+}
+
+#[test]
+fn named_dates() {
+    let mut buffer = [0u8; 32];
+    let i = format_date(784_111_777, &mut buffer);
+    assert_eq!(&buffer[i..], b"Sun, 06 Nov 1994");
+
+    let i = format_date(-62_135_596_800, &mut buffer);
+    assert_eq!(&buffer[i..], b"Mon, 01 Jan 0001");
+
+    let i = format_date(-62_167_219_201, &mut buffer);
+    assert_eq!(&buffer[i..], b"Fri, 31 Dec -0001");
+
+    let epoch = DateTime::default();
+    assert_eq!(epoch, DateTime::from_unix(0));
+    assert_eq!((epoch.weekday_name(), epoch.month_name()), ("Thu", "Jan"));
+}
+
+#[test]
+fn rfc3339_round_trip() {
+    let mut buffer = [0u8; 32];
+    for &secs in &[0, -1, 951_825_599, 253_402_300_799, -62_135_596_800] {
+        let i = format_rfc3339(secs, &mut buffer);
+        assert_eq!(parse_rfc3339(&buffer[i..]), Ok((secs, 0)));
+        assert_eq!(DateTime::from_unix(secs).to_unix(), secs);
+    }
+}
+
+#[test]
+fn rfc3339_parsing() {
+    assert_eq!(parse_rfc3339(b"1970-01-01t00:00:00.123456789123z"), Ok((0, 123_456_789)));
+    assert_eq!(parse_rfc3339(b"1970-01-01 00:00:00-23:59"), Ok((86_340, 0)));
+    assert_eq!(parse_rfc3339(b"2000-02-29T00:00:00Z"), Ok((951_782_400, 0)));
+
+    assert_eq!(parse_rfc3339(b"2024-05-01T12:34:56"), Err(ParseTimestampError::Malformed));
+    assert_eq!(parse_rfc3339(b"2024-05-01T12:34:56.Z"), Err(ParseTimestampError::Malformed));
+    assert_eq!(parse_rfc3339(b"2024-05-01T12:34:56+0200"), Err(ParseTimestampError::Malformed));
+    assert_eq!(parse_rfc3339(b"2024-O5-01T12:34:56Z"), Err(ParseTimestampError::Malformed));
+    assert_eq!(parse_rfc3339(b"1900-02-29T00:00:00Z"), Err(ParseTimestampError::InvalidDate));
+    assert_eq!(parse_rfc3339(b"2024-13-01T00:00:00Z"), Err(ParseTimestampError::InvalidDate));
+    assert_eq!(parse_rfc3339(b"2024-05-01T24:00:00Z"), Err(ParseTimestampError::InvalidTime));
+    assert_eq!(parse_rfc3339(b"2024-05-01T00:00:00+24:00"), Err(ParseTimestampError::InvalidOffset));
+}
+
+#[test]
+fn datetime_policies() {
+    let unix = Policy { years: YearRange::Unix, leap_second: LeapSecond::Reject };
+    let roll = Policy { years: YearRange::Proleptic, leap_second: LeapSecond::Roll };
+
+    assert_eq!(parse_rfc3339(b"2016-12-31T23:59:60Z"), Err(ParseTimestampError::InvalidTime));
+    assert_eq!(parse_rfc3339_with(b"2016-12-31T23:59:60Z", roll), Ok((1_483_228_800, 0)));
+    assert_eq!(parse_rfc3339_with(b"2016-12-31T23:59:61Z", roll), Err(ParseTimestampError::InvalidTime));
+    assert_eq!(parse_rfc3339_with(b"1970-01-01T01:00:00+01:00", unix), Ok((0, 0)));
+    assert_eq!(parse_rfc3339_with(b"9999-12-31T23:59:59-00:01", unix), Err(ParseTimestampError::OutOfRange));
+
+    let mut buffer = [0u8; 24];
+    assert_eq!(try_format_rfc3339(-1, YearRange::Unix, &mut buffer), Err(OutOfRange));
+    assert_eq!(try_format_rfc3339(253_402_300_800, YearRange::Unix, &mut buffer), Err(OutOfRange));
+    assert_eq!(DateTime::try_from_unix(0, YearRange::Unix).map(|time| time.year), Ok(1970));
+
+    assert_eq!(parse_rfc3339(b"0000-01-01T00:00:00+00:01"), Err(ParseTimestampError::OutOfRange));
+    assert_eq!(try_format_rfc3339(-1, YearRange::FourDigit, &mut buffer), Ok(4));
+    assert_eq!(try_format_rfc3339(-62_167_219_201, YearRange::FourDigit, &mut buffer), Err(OutOfRange));
+    assert_eq!(try_format_rfc3339(253_402_300_800, YearRange::FourDigit, &mut buffer), Err(OutOfRange));
+}
+
+#[test]
+fn checked_to_unix() {
+    let leap = DateTime { year: 2000, month: 2, day: 29, hour: 11, minute: 59, second: 59, weekday: 1 };
+    assert_eq!(leap.try_to_unix(YearRange::Unix), Ok(951_825_599));
+    assert_eq!(DateTime::from_unix(i64::MIN).try_to_unix(YearRange::Proleptic), Ok(i64::MIN));
+    assert_eq!(DateTime::from_unix(i64::MAX).try_to_unix(YearRange::Proleptic), Ok(i64::MAX));
+
+    let invalid = [
+        DateTime { month: 0, ..leap },
+        DateTime { month: 13, ..leap },
+        DateTime { day: 0, ..leap },
+        DateTime { day: 32, month: 1, ..leap },
+        DateTime { day: 29, year: 1900, ..leap },
+        DateTime { hour: 24, ..leap },
+        DateTime { minute: 60, ..leap },
+        DateTime { second: 60, ..leap },
+        DateTime { year: i64::MAX, ..leap },
+    ];
+    for time in &invalid {
+        assert_eq!(time.try_to_unix(YearRange::Proleptic), Err(OutOfRange));
+    }
+    assert_eq!(leap.try_to_unix(YearRange::FourDigit), Ok(951_825_599));
+    assert_eq!(DateTime { year: 1969, ..leap }.try_to_unix(YearRange::Unix), Err(OutOfRange));
+
+    // The unchecked conversion carries fields beyond their range over into the next one.
+    assert_eq!(DateTime { hour: 24, ..leap }.to_unix(), DateTime { month: 3, day: 1, hour: 0, ..leap }.to_unix());
+}
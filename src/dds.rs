@@ -0,0 +1,45 @@
+//! Formatting of the frequencies which direct digital synthesizers and fractional PLLs generate, computed from
+//! the increment of their phase accumulator with integer math, so that firmware can show the exact frequency it
+//! configured without floating point.
+//!
+//! ```
+//! use numtoa::dds::format_phase_increment;
+//!
+//! // An increment of 2^28 on a 125 MHz reference clock generates 1/16 of the reference.
+//! let mut buffer = [0u8; 32];
+//! let start = format_phase_increment(1 << 28, 125_000_000, 3, &mut buffer);
+//! assert_eq!(&buffer[start..], b"7812500.000 Hz");
+//! ```
+
+use super::fixed::{convert, Rounding};
+use super::rtl::Rtl;
+
+/// Writes the frequency which a 32-bit phase accumulator generates when it advances by `increment` on each cycle
+/// of a `reference_hz` clock, which is `increment * reference_hz / 2^32`, into the end of the byte slice in hertz
+/// with `decimals` fractional digits, rounded half up. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If `decimals` is above 9, or the supplied buffer is too small to hold the output, this will panic.
+pub fn format_phase_increment(increment: u32, reference_hz: u64, decimals: u32, string: &mut [u8]) -> usize {
+    assert!(decimals <= 9, "numtoa: at most nine decimals are supported");
+    let magnitude = convert(u128::from(increment), u128::from(reference_hz), 1 << 32, decimals, Rounding::HalfUp);
+
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(b" Hz");
+    rtl.fixed(magnitude, decimals);
+    rtl.index()
+}
+
+#[test]
+fn phase_increments() {
+    let mut buffer = [0u8; 40];
+    // The resolution of an AD9850 on a 125 MHz clock.
+    let i = format_phase_increment(1, 125_000_000, 6, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.029104 Hz");
+
+    let i = format_phase_increment(0, 125_000_000, 0, &mut buffer);
+    assert_eq!(&buffer[i..], b"0 Hz");
+
+    let i = format_phase_increment(u32::MAX, u64::MAX, 9, &mut buffer);
+    assert_eq!(&buffer[i..], b"18446744069414584319.000000000 Hz");
+}
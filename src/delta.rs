@@ -0,0 +1,83 @@
+//! Formatting of the change in a value since its last sample, as monitoring interfaces show it, with a marker
+//! before the magnitude which depends on the direction of the change.
+//!
+//! ```
+//! use numtoa::delta::{format_delta, ARROWS, SIGNS};
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_delta(12, &ARROWS, &mut buffer);
+//! assert_eq!(&buffer[start..], "▲ 12".as_bytes());
+//!
+//! let start = format_delta(-3, &SIGNS, &mut buffer);
+//! assert_eq!(&buffer[start..], b"-3");
+//! ```
+
+use core::cmp::Ordering;
+
+use super::rtl::Rtl;
+
+/// The bytes written around the magnitude of a delta, chosen by its direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeltaMarkers<'a> {
+    /// Written before the magnitude of an increase.
+    pub positive: &'a [u8],
+    /// Written before the magnitude of a decrease.
+    pub negative: &'a [u8],
+    /// Written before a zero.
+    pub zero: &'a [u8],
+    /// Written after the magnitude whatever the direction, such as the escape which resets a color.
+    pub suffix: &'a [u8],
+}
+
+/// Marks the direction with a sign, such as `+12`, `-3`, and `0`.
+pub const SIGNS: DeltaMarkers<'static> = DeltaMarkers { positive: b"+", negative: b"-", zero: b"", suffix: b"" };
+
+/// Marks the direction with a triangle, such as `▲ 12`, `▼ 3`, and `0`.
+pub const ARROWS: DeltaMarkers<'static> = DeltaMarkers {
+    positive: "▲ ".as_bytes(),
+    negative: "▼ ".as_bytes(),
+    zero:     b"",
+    suffix:   b"",
+};
+
+/// Marks the direction with a sign and an ANSI color, with increases in green and decreases in red, resetting the
+/// color after the magnitude.
+pub const COLORED_SIGNS: DeltaMarkers<'static> = DeltaMarkers {
+    positive: b"\x1b[32m+",
+    negative: b"\x1b[31m-",
+    zero:     b"\x1b[0m",
+    suffix:   b"\x1b[0m",
+};
+
+/// Writes the magnitude of the delta into the end of the byte slice, preceded by the marker of its direction and
+/// followed by the suffix. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_delta(delta: i64, markers: &DeltaMarkers, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(markers.suffix);
+    rtl.digits(u128::from(delta.unsigned_abs()));
+    rtl.bytes(match delta.cmp(&0) {
+        Ordering::Greater => markers.positive,
+        Ordering::Less    => markers.negative,
+        Ordering::Equal   => markers.zero,
+    });
+    rtl.index()
+}
+
+#[test]
+fn deltas() {
+    let mut buffer = [0u8; 32];
+    let i = format_delta(0, &ARROWS, &mut buffer);
+    assert_eq!(&buffer[i..], b"0");
+
+    let i = format_delta(i64::MIN, &SIGNS, &mut buffer);
+    assert_eq!(&buffer[i..], b"-9223372036854775808");
+
+    let i = format_delta(-40, &ARROWS, &mut buffer);
+    assert_eq!(&buffer[i..], "▼ 40".as_bytes());
+
+    let i = format_delta(5, &COLORED_SIGNS, &mut buffer);
+    assert_eq!(&buffer[i..], b"\x1b[32m+5\x1b[0m");
+}
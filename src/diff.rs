@@ -0,0 +1,128 @@
+//! Differential updates of fixed-width displays, which remember what is shown and report only the runs of
+//! characters which a new value changes, so that displays on slow buses, such as character LCDs over I2C, move
+//! their cursor to and rewrite as few characters as possible on each refresh.
+//!
+//! ```
+//! # #[cfg(feature = "u16")] {
+//! use numtoa::diff::DiffField;
+//! use numtoa::template::Align;
+//!
+//! let mut field = DiffField::<5>::new(Align::Right, b' ');
+//! field.update(1234u16, 10, |_, _| ());
+//!
+//! let mut changes = 0;
+//! field.update(1240u16, 10, |position, bytes| {
+//!     assert_eq!((position, bytes), (3, &b"40"[..]));
+//!     changes += 1;
+//! });
+//! assert_eq!(changes, 1);
+//! assert_eq!(field.shown(), b" 1240");
+//! # }
+//! ```
+
+use super::rtl::SCRATCH_LEN;
+use super::template::{fill_field, Align};
+use super::NumToA;
+
+/// Calls `f` with each run of consecutive bytes in which `current` differs from `previous`, along with the
+/// position of the run within them.
+///
+/// # Panics
+/// If the slices differ in length, this will panic.
+pub fn for_each_change(previous: &[u8], current: &[u8], mut f: impl FnMut(usize, &[u8])) {
+    assert!(previous.len() == current.len(), "numtoa: expected slices of the same length");
+    let mut position = 0;
+    while position < current.len() {
+        if previous[position] == current[position] {
+            position += 1;
+            continue;
+        }
+
+        let end = (position..current.len()).find(|&end| previous[end] == current[end]).unwrap_or(current.len());
+        f(position, &current[position..end]);
+        position = end;
+    }
+}
+
+/// A field of `W` characters on a display, which remembers what it shows so that each update only reports the
+/// characters which change. Until the first update, what the display shows is unknown, so the first update
+/// reports the whole field.
+#[derive(Clone, Debug)]
+pub struct DiffField<const W: usize> {
+    shown: [u8; W],
+    known: bool,
+    align: Align,
+    pad: u8,
+}
+
+impl<const W: usize> DiffField<W> {
+    /// A field whose numbers are aligned to one side and padded with `pad` on the other.
+    pub fn new(align: Align, pad: u8) -> Self {
+        DiffField { shown: [pad; W], known: false, align, pad }
+    }
+
+    /// The characters which the field shows after the last update.
+    pub fn shown(&self) -> &[u8; W] {
+        &self.shown
+    }
+
+    /// Forgets what the field shows, such as after the display was cleared, so that the next update reports the
+    /// whole field.
+    pub fn invalidate(&mut self) {
+        self.known = false;
+    }
+
+    /// Formats the number in the given base into the field, and calls `f` with the position and bytes of each run
+    /// of characters which differ from those shown before.
+    ///
+    /// # Panics
+    /// If the number is wider than the field, this will panic.
+    pub fn update<T: NumToA<B>, B>(&mut self, number: T, base: B, mut f: impl FnMut(usize, &[u8])) {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let start = number.numtoa(base, &mut scratch);
+        let mut current = [0u8; W];
+        fill_field(&mut current, &scratch[start..], self.align, self.pad);
+
+        if self.known {
+            for_each_change(&self.shown, &current, f);
+        } else {
+            f(0, &current);
+        }
+        self.shown = current;
+        self.known = true;
+    }
+}
+
+#[test]
+fn change_runs() {
+    let mut runs = [(0, 0); 4];
+    let mut count = 0;
+    for_each_change(b"12:34:56", b"13:34:07", |position, bytes| {
+        runs[count] = (position, bytes.len());
+        count += 1;
+    });
+    assert_eq!(&runs[..count], &[(1, 1), (6, 2)]);
+
+    for_each_change(b"same", b"same", |_, _| panic!("nothing changed"));
+}
+
+#[test]
+#[cfg(feature = "i32")]
+fn diff_field_updates() {
+    let mut field = DiffField::<4>::new(Align::Left, b'_');
+    let mut reported = 0;
+    field.update(-5i32, 10, |position, bytes| {
+        assert_eq!((position, bytes), (0, &b"-5__"[..]));
+        reported += 1;
+    });
+
+    field.update(-5i32, 10, |_, _| reported += 1);
+    assert_eq!(reported, 1);
+
+    field.invalidate();
+    field.update(-5i32, 10, |_, bytes| {
+        assert_eq!(bytes, b"-5__");
+        reported += 1;
+    });
+    assert_eq!(reported, 2);
+}
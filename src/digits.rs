@@ -0,0 +1,110 @@
+//! Analysis of the digits of a number without writing them into a buffer, for check digit schemes and quick
+//! validation of identifiers.
+//!
+//! ```
+//! use numtoa::digits::{digit_sum, digital_root, for_each_digit};
+//!
+//! assert_eq!(digit_sum(9875, 10), 29);
+//! assert_eq!(digital_root(9875, 10), 2);
+//!
+//! let mut histogram = [0u32; 10];
+//! for_each_digit(1_000_000_007, 10, |digit| histogram[digit as usize] += 1);
+//! assert_eq!((histogram[0], histogram[1], histogram[7]), (8, 1, 1));
+//! ```
+
+// Checks the base, which every function in this module requires to be within `2..=36`.
+fn check_base(base: u32) -> u128 {
+    assert!((2..=36).contains(&base), "numtoa: base must be within 2..=36");
+    u128::from(base)
+}
+
+/// The number of digits in the number when written in the given base, which is one for zero.
+///
+/// # Panics
+/// If the base is not within the range of `2..=36`, this will panic, as do the other functions of this module.
+pub fn digit_count(number: u128, base: u32) -> u32 {
+    let base = check_base(base);
+    let mut count = 1;
+    let mut rest = number / base;
+    while rest != 0 {
+        rest /= base;
+        count += 1;
+    }
+    count
+}
+
+/// Calls `f` with each digit of the number in the given base, from the most significant to the least.
+pub fn for_each_digit(number: u128, base: u32, mut f: impl FnMut(u32)) {
+    let radix = check_base(base);
+    let mut divisor = radix.pow(digit_count(number, base) - 1);
+    while divisor != 0 {
+        f((number / divisor % radix) as u32);
+        divisor /= radix;
+    }
+}
+
+/// The sum of the digits of the number in the given base.
+pub fn digit_sum(number: u128, base: u32) -> u32 {
+    weighted_digit_sum(number, base, &[1])
+}
+
+/// The sum of the digits of the number in the given base, with each digit multiplied by a weight. The weights are
+/// applied from the least significant digit upwards, and repeat when there are more digits than weights, as the
+/// weights of check digit schemes do. Leading zeros contribute nothing, so they need not be represented.
+///
+/// # Panics
+/// If there are no weights, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::digits::weighted_digit_sum;
+///
+/// // The weights of an EAN-13 are 1 for the check digit and then alternate between 3 and 1.
+/// assert_eq!(weighted_digit_sum(4006381333931, 10, &[1, 3]) % 10, 0);
+/// ```
+pub fn weighted_digit_sum(number: u128, base: u32, weights: &[u32]) -> u32 {
+    let radix = check_base(base);
+    assert!(!weights.is_empty(), "numtoa: expected at least one weight");
+    let mut sum = 0;
+    let mut rest = number;
+    for &weight in weights.iter().cycle() {
+        sum += (rest % radix) as u32 * weight;
+        rest /= radix;
+        if rest == 0 {
+            break;
+        }
+    }
+    sum
+}
+
+/// The digital root of the number in the given base, which is the single digit reached by repeatedly summing its
+/// digits.
+pub fn digital_root(number: u128, base: u32) -> u32 {
+    let radix = check_base(base);
+    if number == 0 { 0 } else { (1 + (number - 1) % (radix - 1)) as u32 }
+}
+
+#[test]
+fn digit_analysis() {
+    assert_eq!(digit_count(0, 10), 1);
+    assert_eq!(digit_count(u128::MAX, 10), 39);
+    assert_eq!(digit_count(u128::MAX, 2), 128);
+    assert_eq!(digit_sum(u128::MAX, 2), 128);
+    assert_eq!(digit_sum(0xFF, 16), 30);
+    assert_eq!(digital_root(u128::MAX, 10), digital_root(u128::from(digit_sum(u128::MAX, 10)), 10));
+    assert_eq!(digital_root(0, 10), 0);
+
+    let mut digits = [0u32; 4];
+    let mut count = 0;
+    for_each_digit(0x1A3, 16, |digit| {
+        digits[count] = digit;
+        count += 1;
+    });
+    assert_eq!(&digits[..count], &[1, 10, 3]);
+}
+
+#[test]
+#[should_panic]
+fn digit_base_out_of_range() {
+    digit_sum(10, 1);
+}
@@ -0,0 +1,89 @@
+//! The DTMF tone pairs which dial the digits of a number, for telephony firmware dialing values it computes at
+//! runtime. Each key of the keypad sounds the frequency of its row together with the frequency of its column.
+//!
+//! ```
+//! use numtoa::dtmf::{numtoa_dtmf, Tone};
+//!
+//! let mut tones = [Tone::default(); 8];
+//! let start = numtoa_dtmf(911, &mut tones);
+//! assert_eq!(tones[start], Tone { row: 852, column: 1477 });
+//! assert_eq!(tones[start + 1], Tone { row: 697, column: 1209 });
+//! assert_eq!(tones.len() - start, 3);
+//! ```
+
+const ROWS: [u16; 4] = [697, 770, 852, 941];
+const COLUMNS: [u16; 4] = [1209, 1336, 1477, 1633];
+
+// The keypad, row by row.
+const KEYS: [[u8; 4]; 4] = [*b"123A", *b"456B", *b"789C", *b"*0#D"];
+
+/// The pair of frequencies, in hertz, which sound together to dial one key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Tone {
+    /// The low frequency, of the key's row.
+    pub row: u16,
+    /// The high frequency, of the key's column.
+    pub column: u16,
+}
+
+impl Tone {
+    /// The tone pair of a key of the keypad, which are the ASCII digits, `*`, `#`, and `A` through `D`.
+    pub fn for_key(key: u8) -> Option<Tone> {
+        let key = key.to_ascii_uppercase();
+        KEYS.iter().enumerate().find_map(|(row, keys)| {
+            keys.iter().position(|&k| k == key).map(|column| Tone { row: ROWS[row], column: COLUMNS[column] })
+        })
+    }
+
+    /// The tone pair of a decimal digit.
+    ///
+    /// # Panics
+    /// If the digit is above 9, this will panic.
+    pub fn for_digit(digit: u8) -> Tone {
+        assert!(digit < 10, "numtoa: expected a decimal digit");
+        Tone::for_key(b'0' + digit).unwrap()
+    }
+}
+
+/// Writes the tone pairs which dial the decimal digits of the number into the end of the slice, returning the
+/// indice where the first tone begins.
+///
+/// # Panics
+/// If the supplied slice is too small to hold a tone for every digit, this will panic.
+pub fn numtoa_dtmf(number: u128, tones: &mut [Tone]) -> usize {
+    let mut index = tones.len();
+    let mut rest = number;
+    loop {
+        index = index.checked_sub(1).expect("numtoa: buffer is too small");
+        tones[index] = Tone::for_digit((rest % 10) as u8);
+        rest /= 10;
+        if rest == 0 {
+            break index;
+        }
+    }
+}
+
+#[test]
+fn dtmf_keys() {
+    assert_eq!(Tone::for_key(b'0'), Some(Tone { row: 941, column: 1336 }));
+    assert_eq!(Tone::for_key(b'#'), Some(Tone { row: 941, column: 1477 }));
+    assert_eq!(Tone::for_key(b'd'), Some(Tone { row: 941, column: 1633 }));
+    assert_eq!(Tone::for_key(b'E'), None);
+}
+
+#[test]
+fn dtmf_digits() {
+    let mut tones = [Tone::default(); 4];
+    let i = numtoa_dtmf(0, &mut tones);
+    assert_eq!(&tones[i..], &[Tone::for_key(b'0').unwrap()]);
+
+    let i = numtoa_dtmf(2580, &mut tones);
+    assert_eq!(i, 0);
+    assert!(tones.iter().all(|tone| tone.column == 1336));
+}
+
+#[test]
+#[should_panic]
+fn dtmf_too_small() {
+    numtoa_dtmf(100, &mut [Tone::default(); 2]);
+}
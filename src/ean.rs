@@ -0,0 +1,103 @@
+//! Check digits of EAN-13 barcodes and the ISBN-13 numbers of books, which share the same scheme, along with
+//! their conventional printed groupings.
+//!
+//! ```
+//! use numtoa::ean::{ean13_check_digit, format_isbn13, is_valid_ean13};
+//!
+//! assert_eq!(ean13_check_digit(978_316_148_410), 0);
+//! assert!(is_valid_ean13(9_783_161_484_100));
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_isbn13(9_783_161_484_100, 1, 2, &mut buffer);
+//! assert_eq!(&buffer[start..], b"978-3-16-148410-0");
+//! ```
+
+use super::digits::weighted_digit_sum;
+use super::rtl::Rtl;
+
+// One more than the largest EAN-13.
+const EAN13_LIMIT: u64 = 10_000_000_000_000;
+
+/// Computes the check digit which completes the first twelve digits of an EAN-13 or ISBN-13.
+///
+/// # Panics
+/// If the number has more than twelve digits, this will panic.
+pub fn ean13_check_digit(first_twelve: u64) -> u8 {
+    assert!(first_twelve < EAN13_LIMIT / 10, "numtoa: expected at most twelve digits");
+    let sum = weighted_digit_sum(u128::from(first_twelve), 10, &[3, 1]);
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// Checks whether the number is an EAN-13 or ISBN-13 of at most thirteen digits whose last digit is its check digit.
+pub fn is_valid_ean13(code: u64) -> bool {
+    code < EAN13_LIMIT && ean13_check_digit(code / 10) == (code % 10) as u8
+}
+
+/// Writes an EAN-13 into the end of the byte slice as it is printed beneath its barcode, which is the first digit,
+/// then two groups of six, such as `4 006381 333931`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the number has more than thirteen digits, or the supplied buffer is too small to hold the output, this will
+/// panic.
+pub fn format_ean13(code: u64, string: &mut [u8]) -> usize {
+    assert!(code < EAN13_LIMIT, "numtoa: expected at most thirteen digits");
+    let code = u128::from(code);
+    let mut rtl = Rtl::new(string);
+    rtl.digits_padded(code % 1_000_000, 6, b'0');
+    rtl.byte(b' ');
+    rtl.digits_padded(code / 1_000_000 % 1_000_000, 6, b'0');
+    rtl.byte(b' ');
+    rtl.digits(code / 1_000_000_000_000);
+    rtl.index()
+}
+
+/// Writes an ISBN-13 into the end of the byte slice with hyphens between its prefix, registration group,
+/// registrant, publication, and check digit, such as `978-3-16-148410-0`. The lengths of the group and registrant
+/// vary with the ranges assigned by the International ISBN Agency, and so are given, while the publication takes
+/// the remaining digits. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the number has more than thirteen digits, if the group and registrant leave no digits for the publication,
+/// or if the supplied buffer is too small to hold the output, this will panic.
+pub fn format_isbn13(code: u64, group_len: usize, registrant_len: usize, string: &mut [u8]) -> usize {
+    assert!(code < EAN13_LIMIT, "numtoa: expected at most thirteen digits");
+    assert!(group_len >= 1 && registrant_len >= 1 && group_len + registrant_len < 9, "numtoa: invalid ISBN groups");
+    let publication_len = 9 - group_len - registrant_len;
+
+    let mut rtl = Rtl::new(string);
+    let mut rest = u128::from(code);
+    rtl.digits(rest % 10);
+    rest /= 10;
+    for &len in &[publication_len, registrant_len, group_len, 3] {
+        let divisor = 10u128.pow(len as u32);
+        rtl.byte(b'-');
+        rtl.digits_padded(rest % divisor, len, b'0');
+        rest /= divisor;
+    }
+    rtl.index()
+}
+
+#[test]
+fn ean13_check_digits() {
+    assert_eq!(ean13_check_digit(400_638_133_393), 1);
+    assert_eq!(ean13_check_digit(0), 0);
+    assert!(is_valid_ean13(4_006_381_333_931));
+    assert!(!is_valid_ean13(4_006_381_333_932));
+    assert!(!is_valid_ean13(40_063_813_339_310));
+}
+
+#[test]
+fn ean13_groups() {
+    let mut buffer = [0u8; 24];
+    let i = format_ean13(4_006_381_333_931, &mut buffer);
+    assert_eq!(&buffer[i..], b"4 006381 333931");
+
+    let i = format_ean13(12, &mut buffer);
+    assert_eq!(&buffer[i..], b"0 000000 000012");
+
+    let i = format_isbn13(9_780_306_406_157, 1, 3, &mut buffer);
+    assert_eq!(&buffer[i..], b"978-0-306-40615-7");
+
+    let i = format_isbn13(9_791_034_304_057, 2, 4, &mut buffer);
+    assert_eq!(&buffer[i..], b"979-10-3430-405-7");
+}
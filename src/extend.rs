@@ -0,0 +1,81 @@
+//! Appending numbers onto the end of growable byte buffers, such as the `SmallVec` and `tinyvec::ArrayVec` types
+//! that parsers and serializers commonly buffer into.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use numtoa::extend::ExtendNumToA;
+//!
+//! let mut line = b"temp=".to_vec();
+//! line.extend_numtoa(-7i16, 10);
+//! assert_eq!(line, b"temp=-7");
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+#[cfg(feature = "tinyvec")]
+use tinyvec::ArrayVec;
+
+use super::rtl::SCRATCH_LEN;
+use super::NumToA;
+
+/// A byte buffer which numbers can be appended onto. Implementing `extend_bytes` for another buffer type
+/// provides `extend_numtoa` for it as well.
+pub trait ExtendNumToA {
+    /// Appends the bytes to the end of the buffer.
+    fn extend_bytes(&mut self, bytes: &[u8]);
+
+    /// Appends the number in the given base to the end of the buffer.
+    ///
+    /// # Panics
+    /// If the number requires more than 129 bytes, which no primitive integer does, or the buffer has a fixed
+    /// capacity which would be exceeded, this will panic.
+    fn extend_numtoa<T: NumToA<B>, B>(&mut self, number: T, base: B) {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let start = number.numtoa(base, &mut scratch);
+        self.extend_bytes(&scratch[start..]);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ExtendNumToA for Vec<u8> {
+    fn extend_bytes(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array<Item = u8>> ExtendNumToA for SmallVec<A> {
+    fn extend_bytes(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array<Item = u8>> ExtendNumToA for ArrayVec<A> {
+    fn extend_bytes(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "smallvec", feature = "u32"))]
+fn extend_smallvec() {
+    let mut vec = SmallVec::<[u8; 8]>::new();
+    vec.extend_numtoa(0xC0FFEEu32, 16);
+    vec.extend_numtoa(12345678u32, 10);
+    assert_eq!(&vec[..], b"C0FFEE12345678");
+    assert!(vec.spilled());
+}
+
+#[test]
+#[cfg(all(feature = "tinyvec", feature = "u8"))]
+fn extend_arrayvec() {
+    let mut vec = ArrayVec::<[u8; 12]>::new();
+    vec.extend_numtoa(200u8, 10);
+    vec.push(b',');
+    vec.extend_numtoa(200u8, 2);
+    assert_eq!(&vec[..], b"200,11001000");
+}
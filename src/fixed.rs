@@ -0,0 +1,162 @@
+//! Formatting of scaled integers as fixed-point decimals, such as millivolts being printed as volts, using only
+//! integer math.
+//!
+//! ```
+//! use numtoa::fixed::{format_scaled, Rounding};
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_scaled(-12345, 3, 2, Rounding::HalfUp, &mut buffer);
+//! assert_eq!(&buffer[start..], b"-12.35");
+//! ```
+
+use super::rtl::Rtl;
+
+/// Determines how a value is rounded when digits must be dropped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Drops the excess digits, rounding towards zero.
+    Truncate,
+    /// Rounds to the nearest value, with ties rounding away from zero.
+    #[default]
+    HalfUp,
+    /// Rounds to the nearest value, with ties rounding to the even neighbour.
+    HalfEven,
+    /// Rounds away from zero whenever any excess digit is not zero.
+    Up,
+}
+
+impl Rounding {
+    /// Divides a magnitude, rounding the quotient according to this mode.
+    ///
+    /// # Panics
+    /// If the divisor is zero, this will panic.
+    pub fn div(self, dividend: u128, divisor: u128) -> u128 {
+        let (quotient, rem) = (dividend / divisor, dividend % divisor);
+        let round_up = match self {
+            Rounding::Truncate => false,
+            Rounding::HalfUp   => rem >= divisor - rem,
+            Rounding::HalfEven => rem > divisor - rem || (rem == divisor - rem && quotient % 2 == 1),
+            Rounding::Up       => rem != 0,
+        };
+        quotient + round_up as u128
+    }
+}
+
+// Multiplies a magnitude by `numerator` and divides it by `denominator` into one with `decimals` implied
+// decimals, rounding only once at the end, so that the conversion is exact until the last digit. Every scaling
+// of the crate goes through here, so that overflows are caught in one place.
+//
+// Panics if the denominator is zero, or the magnitude overflows a `u128` before it is divided.
+pub(crate) fn convert(magnitude: u128, numerator: u128, denominator: u128, decimals: u32, rounding: Rounding) -> u128 {
+    let product = magnitude.checked_mul(numerator).expect("numtoa: fixed-point value overflows");
+    if product == 0 {
+        return 0;
+    }
+
+    let dividend = 10u128.checked_pow(decimals)
+        .and_then(|factor| product.checked_mul(factor))
+        .expect("numtoa: fixed-point value overflows");
+    rounding.div(dividend, denominator)
+}
+
+// Rescales a magnitude with `scale` implied decimals to one with `decimals` implied decimals.
+//
+// Panics if the rescaled magnitude does not fit in a `u128`.
+pub(crate) fn rescale(magnitude: u128, scale: u32, decimals: u32, rounding: Rounding) -> u128 {
+    if decimals >= scale {
+        return convert(magnitude, 1, 1, decimals - scale, rounding);
+    }
+
+    match 10u128.checked_pow(scale - decimals) {
+        Some(divisor) => rounding.div(magnitude, divisor),
+        // Every magnitude is below half of a divisor this large, so only rounding up keeps anything.
+        None => (rounding == Rounding::Up && magnitude != 0) as u128,
+    }
+}
+
+// Writes a magnitude with `decimals` implied decimals, preceded by a minus when it is negative and not zero.
+fn write_signed(rtl: &mut Rtl, negative: bool, magnitude: u128, decimals: u32) {
+    rtl.fixed(magnitude, decimals);
+    if negative && magnitude != 0 {
+        rtl.byte(b'-');
+    }
+}
+
+// Writes a signed fixed-point value, which is the composition that most of the unit formatters need. A value
+// which rounds to zero is written without a sign.
+pub(crate) fn write_scaled(rtl: &mut Rtl, value: i64, scale: u32, decimals: u32, rounding: Rounding) {
+    let magnitude = rescale(u128::from(value.unsigned_abs()), scale, decimals, rounding);
+    write_signed(rtl, value < 0, magnitude, decimals);
+}
+
+// Writes a signed value multiplied by `numerator / denominator` as a fixed-point value, as `convert` computes it.
+// A value which rounds to zero is written without a sign.
+pub(crate) fn write_converted(
+    rtl: &mut Rtl,
+    value: i64,
+    numerator: u128,
+    denominator: u128,
+    decimals: u32,
+    rounding: Rounding,
+) {
+    let magnitude = convert(u128::from(value.unsigned_abs()), numerator, denominator, decimals, rounding);
+    write_signed(rtl, value < 0, magnitude, decimals);
+}
+
+/// Writes `value / 10^scale` into the end of the byte slice as a decimal with exactly `decimals` fractional
+/// digits, returning the indice where the inner string begins. Excess digits are dropped according to the
+/// rounding mode, and a value which rounds to zero is written without a sign.
+///
+/// # Panics
+/// If `decimals` exceeds `scale` by so much that the value no longer fits in a `u128`, or the supplied buffer is
+/// too small to hold the output, this will panic.
+pub fn format_scaled(value: i64, scale: u32, decimals: u32, rounding: Rounding, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    write_scaled(&mut rtl, value, scale, decimals, rounding);
+    rtl.index()
+}
+
+#[test]
+fn scaled_rounding() {
+    let mut buffer = [0u8; 32];
+    let i = format_scaled(3300, 3, 3, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"3.300");
+
+    let i = format_scaled(3300, 3, 0, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"3");
+
+    let i = format_scaled(25, 1, 0, Rounding::HalfEven, &mut buffer);
+    assert_eq!(&buffer[i..], b"2");
+
+    let i = format_scaled(-25, 1, 0, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"-3");
+
+    let i = format_scaled(-4, 3, 2, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.00");
+
+    let i = format_scaled(1, 3, 1, Rounding::Up, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.1");
+
+    let i = format_scaled(i64::MIN, 0, 2, Rounding::Truncate, &mut buffer);
+    assert_eq!(&buffer[i..], b"-9223372036854775808.00");
+}
+
+#[test]
+fn scaled_extremes() {
+    let mut buffer = [0u8; 64];
+    let i = format_scaled(i64::MAX, 40, 2, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.00");
+
+    let i = format_scaled(-1, 40, 2, Rounding::Up, &mut buffer);
+    assert_eq!(&buffer[i..], b"-0.01");
+
+    let i = format_scaled(0, 0, 40, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.0000000000000000000000000000000000000000");
+}
+
+#[test]
+#[should_panic(expected = "numtoa: fixed-point value overflows")]
+fn scaled_overflow() {
+    let mut buffer = [0u8; 64];
+    format_scaled(i64::MAX, 0, 20, Rounding::HalfUp, &mut buffer);
+}
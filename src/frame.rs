@@ -0,0 +1,114 @@
+//! A writer for fixed message layouts, such as fixed-size protocol frames, which tracks the remaining capacity of
+//! the frame in its type. Each field consumes the writer and returns one with that much less capacity, and a
+//! layout which does not add up to the size of the frame fails to build rather than panicking at runtime.
+//!
+//! ```
+//! # #[cfg(all(feature = "u16", feature = "i32"))] {
+//! use numtoa::frame::{Frame, Width};
+//!
+//! let mut buffer = [0u8; 12];
+//! let frame: Frame<7> = Frame::new(&mut buffer).write_num(Width::<5>, 1234u16, 10, b'0');
+//! let frame: Frame<6> = frame.write_bytes(b",");
+//! frame.write_num(Width::<6>, -42i32, 10, b' ').finish();
+//! assert_eq!(&buffer, b"01234,   -42");
+//! # }
+//! ```
+//!
+//! The layout is checked when the code is built, so a frame which is too small for its fields is an error.
+//!
+//! ```compile_fail
+//! use numtoa::frame::{Frame, Width};
+//!
+//! let mut buffer = [0u8; 4];
+//! Frame::new(&mut buffer).write_num(Width::<5>, 1234u16, 10, b'0').finish();
+//! ```
+
+use super::rtl::SCRATCH_LEN;
+use super::template::{fill_field, Align};
+use super::NumToA;
+
+// Fills the field with the number in the given base, right-aligned and padded on the left with `pad`.
+pub(crate) fn write_padded<T: NumToA<B>, B>(field: &mut [u8], number: T, base: B, pad: u8) {
+    let mut scratch = [0u8; SCRATCH_LEN];
+    let start = number.numtoa(base, &mut scratch);
+    fill_field(field, &scratch[start..], Align::Right, pad);
+}
+
+// Checks that a field of `W` bytes taken from a frame of `N` bytes leaves `R` bytes remaining. The assertion is
+// evaluated when the writer is monomorphized, which happens during a build rather than `cargo check`.
+struct Split<const N: usize, const W: usize, const R: usize>;
+
+impl<const N: usize, const W: usize, const R: usize> Split<N, W, R> {
+    const CHECK: () = assert!(W <= N && N - W == R, "numtoa: the fields do not add up to the size of the frame");
+}
+
+/// The width in bytes of a numeric field, given as a type so that it is known when the code is built.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Width<const W: usize>;
+
+/// A writer over a frame with `N` bytes remaining, which is written from left to right one field at a time.
+pub struct Frame<'a, const N: usize> {
+    string: &'a mut [u8],
+}
+
+impl<'a, const N: usize> Frame<'a, N> {
+    /// Begins writing at the start of the buffer.
+    pub fn new(buffer: &'a mut [u8; N]) -> Self {
+        Frame { string: buffer }
+    }
+
+    fn split<const W: usize, const R: usize>(self) -> (&'a mut [u8], Frame<'a, R>) {
+        #[allow(clippy::let_unit_value)]
+        let () = Split::<N, W, R>::CHECK;
+        let (field, rest) = self.string.split_at_mut(W);
+        (field, Frame { string: rest })
+    }
+
+    /// Writes the number in the given base into the next `W` bytes, right-aligned and padded on the left with
+    /// `pad`, returning the writer for the remainder of the frame.
+    ///
+    /// # Panics
+    /// If the number has more digits than the width of the field, this will panic.
+    pub fn write_num<const W: usize, const R: usize, T: NumToA<B>, B>(
+        self,
+        _width: Width<W>,
+        number: T,
+        base: B,
+        pad: u8,
+    ) -> Frame<'a, R> {
+        let (field, rest) = self.split::<W, R>();
+        write_padded(field, number, base, pad);
+        rest
+    }
+
+    /// Writes the bytes into the next `W` bytes, such as a separator or a fixed header, returning the writer for
+    /// the remainder of the frame.
+    pub fn write_bytes<const W: usize, const R: usize>(self, bytes: &[u8; W]) -> Frame<'a, R> {
+        let (field, rest) = self.split::<W, R>();
+        field.copy_from_slice(bytes);
+        rest
+    }
+}
+
+impl Frame<'_, 0> {
+    /// Ends a frame which has been completely written.
+    pub fn finish(self) {}
+}
+
+#[test]
+#[cfg(feature = "u8")]
+fn frame_fields() {
+    let mut buffer = [0u8; 8];
+    let frame: Frame<5> = Frame::new(&mut buffer).write_bytes(b"ID=");
+    let frame: Frame<2> = frame.write_num(Width::<3>, 7u8, 10, b'0');
+    frame.write_num(Width::<2>, 255u8, 16, b' ').finish();
+    assert_eq!(&buffer, b"ID=007FF");
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "u8")]
+fn frame_field_overflow() {
+    let mut buffer = [0u8; 2];
+    Frame::new(&mut buffer).write_num(Width::<2>, 255u8, 10, b'0').finish();
+}
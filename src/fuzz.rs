@@ -0,0 +1,104 @@
+//! `Arbitrary` implementations for the options of the formatters and parsers, so that fuzzers are able to drive
+//! every configuration of them from raw bytes, alongside the numbers themselves.
+//!
+//! The parse options are the `Policy` of the timestamp parsers and the base-tagged wrappers of `radix`. Options
+//! which borrow their bytes, such as `Unit` and `DeltaMarkers`, are left for the fuzz target to build.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::ansi::{Color, Thresholds};
+use super::bar::BarStyle;
+use super::datetime::{LeapSecond, Policy, YearRange};
+use super::fixed::Rounding;
+use super::geo::Axis;
+use super::glyph::Glyphs;
+use super::hexline::RecordType;
+use super::human::{NumberScale, PowerStyle, SquareStyle};
+use super::plural::PluralRules;
+use super::radix::{Bin, Hex, Oct};
+use super::ratio::RatioUnit;
+use super::sci::{ExponentFormat, Notation};
+use super::template::Align;
+use super::unicode::{DigitForm, Script};
+use super::units::{DecibelUnit, DegreeSymbol, PressureUnit};
+
+// Chooses any of the variants of a fieldless enum.
+macro_rules! impl_arbitrary_for_enum {
+    ($t:ident, [$($variant:ident),*]) => {
+        impl<'a> Arbitrary<'a> for $t {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                u.choose(&[$($t::$variant),*]).copied()
+            }
+        }
+    }
+}
+
+impl_arbitrary_for_enum!(Align, [Left, Right]);
+impl_arbitrary_for_enum!(Axis, [Latitude, Longitude]);
+impl_arbitrary_for_enum!(BarStyle, [Ascii, Blocks]);
+impl_arbitrary_for_enum!(Color, [Black, Red, Green, Yellow, Blue, Magenta, Cyan, White]);
+impl_arbitrary_for_enum!(DecibelUnit, [Db, Dbm, Dbfs]);
+impl_arbitrary_for_enum!(DegreeSymbol, [Unicode, Ascii, None]);
+impl_arbitrary_for_enum!(DigitForm, [Fullwidth, Superscript, Subscript]);
+impl_arbitrary_for_enum!(LeapSecond, [Reject, Clamp, Roll]);
+impl_arbitrary_for_enum!(Notation, [Scientific, Engineering]);
+impl_arbitrary_for_enum!(NumberScale, [Short, Long]);
+impl_arbitrary_for_enum!(PluralRules, [English, EastSlavic, Polish, CzechSlovak]);
+impl_arbitrary_for_enum!(PowerStyle, [Caret, E]);
+impl_arbitrary_for_enum!(PressureUnit, [Pascal, Hectopascal, InchesOfMercury, Psi]);
+impl_arbitrary_for_enum!(RatioUnit, [Percent, PerMille, PartsPerMillion]);
+impl_arbitrary_for_enum!(RecordType, [Data, EndOfFile, ExtendedSegmentAddress, StartSegmentAddress,
+                                      ExtendedLinearAddress, StartLinearAddress]);
+impl_arbitrary_for_enum!(Rounding, [Truncate, HalfUp, HalfEven, Up]);
+impl_arbitrary_for_enum!(SquareStyle, [Caret, Superscript]);
+impl_arbitrary_for_enum!(Script, [Latin, ArabicIndic, ExtendedArabicIndic, Devanagari, Bengali, Thai, Khmer]);
+impl_arbitrary_for_enum!(YearRange, [Proleptic, Unix, FourDigit]);
+
+impl<'a> Arbitrary<'a> for ExponentFormat {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ExponentFormat { uppercase: u.arbitrary()?, force_sign: u.arbitrary()?, min_digits: u.arbitrary()? })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Glyphs {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Glyphs { minus: u.arbitrary()?, blank: u.arbitrary()? })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Policy {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Policy { years: u.arbitrary()?, leap_second: u.arbitrary()? })
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Thresholds<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Thresholds { warning: u.arbitrary()?, critical: u.arbitrary()? })
+    }
+}
+
+// The wrappers which tag an integer with the base that it is parsed from.
+macro_rules! impl_arbitrary_for_wrapper {
+    ($($t:ident),*) => {$(
+        impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for $t<T> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                u.arbitrary().map($t)
+            }
+        }
+    )*}
+}
+
+impl_arbitrary_for_wrapper!(Bin, Hex, Oct);
+
+#[test]
+fn arbitrary_options() {
+    let mut u = Unstructured::new(&[0, 1, 2, 3]);
+    let roundings: [Rounding; 4] = core::array::from_fn(|_| Rounding::arbitrary(&mut u).unwrap());
+    assert_eq!(roundings, [Rounding::Truncate, Rounding::HalfUp, Rounding::HalfEven, Rounding::Up]);
+
+    let mut u = Unstructured::new(&[1, 2, 0x34, 0x12]);
+    let policy = Policy::arbitrary(&mut u).unwrap();
+    assert_eq!(policy, Policy { years: YearRange::Unix, leap_second: LeapSecond::Roll });
+    assert_eq!(Hex::<u16>::arbitrary(&mut u).unwrap(), Hex(0x1234));
+}
@@ -0,0 +1,80 @@
+//! Formatting of coordinates for mapping interfaces, written as decimal degrees with their hemisphere. Angles in
+//! degrees, minutes, and seconds are written with `positional::format_sexagesimal` instead.
+//!
+//! ```
+//! use numtoa::geo::{format_latlon_decimal, Axis};
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_latlon_decimal(48_858_222, Axis::Latitude, 6, &mut buffer);
+//! assert_eq!(&buffer[start..], "48.858222° N".as_bytes());
+//!
+//! let start = format_latlon_decimal(-2_294_481, Axis::Longitude, 4, &mut buffer);
+//! assert_eq!(&buffer[start..], "2.2945° W".as_bytes());
+//! ```
+
+use super::fixed::{rescale, Rounding};
+use super::rtl::Rtl;
+
+/// Which of the two coordinates an angle is, which decides its hemispheres.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// An angle north or south of the equator, within 90 degrees.
+    Latitude,
+    /// An angle east or west of the prime meridian, within 180 degrees.
+    Longitude,
+}
+
+impl Axis {
+    fn limit(self) -> u32 {
+        match self {
+            Axis::Latitude  => 90_000_000,
+            Axis::Longitude => 180_000_000,
+        }
+    }
+
+    fn hemisphere(self, negative: bool) -> &'static [u8] {
+        match (self, negative) {
+            (Axis::Latitude, false)  => "° N".as_bytes(),
+            (Axis::Latitude, true)   => "° S".as_bytes(),
+            (Axis::Longitude, false) => "° E".as_bytes(),
+            (Axis::Longitude, true)  => "° W".as_bytes(),
+        }
+    }
+}
+
+/// Writes an angle in millionths of a degree into the end of the byte slice as unsigned decimal degrees, rounded
+/// half up to `decimals` fractional digits, followed by its hemisphere, such as `48.858222° N`. An angle of zero,
+/// or one which rounds to zero, is written as north or east. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the angle is beyond the range of its axis, `decimals` is so large that the angle no longer fits in a `u128`,
+/// or the supplied buffer is too small to hold the output, this will panic.
+pub fn format_latlon_decimal(micro_deg: i32, axis: Axis, decimals: u32, string: &mut [u8]) -> usize {
+    assert!(micro_deg.unsigned_abs() <= axis.limit(), "numtoa: the coordinate is out of range");
+    let magnitude = rescale(u128::from(micro_deg.unsigned_abs()), 6, decimals, Rounding::HalfUp);
+
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(axis.hemisphere(micro_deg < 0 && magnitude != 0));
+    rtl.fixed(magnitude, decimals);
+    rtl.index()
+}
+
+#[test]
+fn decimal_coordinates() {
+    let mut buffer = [0u8; 24];
+    let i = format_latlon_decimal(-90_000_000, Axis::Latitude, 2, &mut buffer);
+    assert_eq!(&buffer[i..], "90.00° S".as_bytes());
+
+    let i = format_latlon_decimal(-4, Axis::Longitude, 5, &mut buffer);
+    assert_eq!(&buffer[i..], "0.00000° E".as_bytes());
+
+    let i = format_latlon_decimal(180_000_000, Axis::Longitude, 7, &mut buffer);
+    assert_eq!(&buffer[i..], "180.0000000° E".as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn latitude_out_of_range() {
+    let mut buffer = [0u8; 24];
+    format_latlon_decimal(90_000_001, Axis::Latitude, 6, &mut buffer);
+}
@@ -0,0 +1,70 @@
+//! Output for framebuffer interfaces which draw numbers from a font of digit glyphs, where each digit is written
+//! as the index of its glyph rather than as ASCII.
+//!
+//! ```
+//! # #[cfg(feature = "i32")] {
+//! use numtoa::glyph::{numtoa_glyphs, Glyphs};
+//!
+//! // A font of the glyphs 0 through 9, then a minus sign, then a blank.
+//! let mut cells = [0u8; 6];
+//! let start = numtoa_glyphs(-305i32, 10, Glyphs::default(), &mut cells);
+//! assert_eq!(cells, [11, 11, 10, 3, 0, 5]);
+//! assert_eq!(start, 2);
+//! # }
+//! ```
+
+use super::rtl::SCRATCH_LEN;
+use super::NumToA;
+
+/// The glyph indices of the minus sign and the blank cell. The digits always take the indices of their values,
+/// so a font for bases above 10 must place these after its last digit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Glyphs {
+    /// The index of the minus sign.
+    pub minus: u8,
+    /// The index of the blank cell, which fills the cells before the number.
+    pub blank: u8,
+}
+
+impl Default for Glyphs {
+    /// The layout of a decimal font, with the minus sign at 10 and the blank at 11.
+    fn default() -> Self {
+        Glyphs { minus: 10, blank: 11 }
+    }
+}
+
+/// Writes the number in the given base into the end of the slice as glyph indices, with each digit written as its
+/// value, and fills the cells before it with the blank glyph so that the whole slice can be drawn. Returns the
+/// indice where the number begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the number, this will panic.
+pub fn numtoa_glyphs<T: NumToA<B>, B>(number: T, base: B, glyphs: Glyphs, cells: &mut [u8]) -> usize {
+    let mut scratch = [0u8; SCRATCH_LEN];
+    let start = number.numtoa(base, &mut scratch);
+    let digits = &scratch[start..];
+    let begin = cells.len().checked_sub(digits.len()).expect("numtoa: buffer is too small");
+
+    let (blank, number) = cells.split_at_mut(begin);
+    blank.iter_mut().for_each(|cell| *cell = glyphs.blank);
+    for (cell, &byte) in number.iter_mut().zip(digits) {
+        *cell = match byte {
+            b'-' => glyphs.minus,
+            b'0'..=b'9' => byte - b'0',
+            _ => byte - b'A' + 10,
+        };
+    }
+    begin
+}
+
+#[test]
+#[cfg(feature = "u16")]
+fn glyph_indices() {
+    let mut cells = [0xFFu8; 4];
+    let i = numtoa_glyphs(0xBEEFu16, 16, Glyphs { minus: 16, blank: 17 }, &mut cells);
+    assert_eq!((i, cells), (0, [11, 14, 14, 15]));
+
+    let mut cells = [0xFFu8; 3];
+    let i = numtoa_glyphs(0u16, 10, Glyphs::default(), &mut cells);
+    assert_eq!((i, cells), (2, [11, 11, 0]));
+}
@@ -0,0 +1,88 @@
+//! Line formats which carry bytes as pairs of hexadecimal digits between a leading `:` and a trailing CRLF, with a
+//! two's complement checksum: Intel HEX records and Modbus ASCII frames.
+//!
+//! ```
+//! use numtoa::hexline::{format_intel_hex, format_modbus_ascii, RecordType};
+//!
+//! let mut buffer = [0u8; 64];
+//! let start = format_intel_hex(RecordType::Data, 0x0030, &[0x02, 0x33, 0x7A], &mut buffer);
+//! assert_eq!(&buffer[start..], b":0300300002337A1E\r\n");
+//!
+//! // Read two holding registers from address 0x006B of device 17.
+//! let start = format_modbus_ascii(0x11, &[0x03, 0x00, 0x6B, 0x00, 0x02], &mut buffer);
+//! assert_eq!(&buffer[start..], b":1103006B00027F\r\n");
+//! ```
+
+use super::rtl::Rtl;
+
+/// The type of an Intel HEX record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RecordType {
+    /// Data bytes at the 16-bit address of the record.
+    Data = 0x00,
+    /// The last record of the file, which has no data.
+    EndOfFile = 0x01,
+    /// The segment base address, which is shifted left by four and added to the addresses that follow.
+    ExtendedSegmentAddress = 0x02,
+    /// The initial values of the `CS:IP` registers.
+    StartSegmentAddress = 0x03,
+    /// The upper 16 bits of the addresses that follow.
+    ExtendedLinearAddress = 0x04,
+    /// The initial value of the `EIP` register.
+    StartLinearAddress = 0x05,
+}
+
+// Writes `:`, the header and data as hexadecimal pairs, the two's complement of the sum of every byte, and CRLF.
+fn format_line(header: &[u8], data: &[u8], string: &mut [u8]) -> usize {
+    let sum = header.iter().chain(data).fold(0u8, |sum, &byte| sum.wrapping_add(byte));
+
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(b"\r\n");
+    rtl.hex_byte(sum.wrapping_neg());
+    data.iter().rev().chain(header.iter().rev()).for_each(|&byte| rtl.hex_byte(byte));
+    rtl.byte(b':');
+    rtl.index()
+}
+
+/// Writes an Intel HEX record of the given type, address, and data into the end of the byte slice, such as
+/// `:0300300002337A1E` followed by CRLF. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If there are more than 255 bytes of data, or the supplied buffer is too small to hold the record, which takes
+/// `13 + 2 * data.len()` bytes, this will panic.
+pub fn format_intel_hex(record_type: RecordType, address: u16, data: &[u8], string: &mut [u8]) -> usize {
+    assert!(data.len() <= 255, "numtoa: an Intel HEX record holds at most 255 bytes");
+    let [high, low] = address.to_be_bytes();
+    format_line(&[data.len() as u8, high, low, record_type as u8], data, string)
+}
+
+/// Writes a Modbus ASCII frame for the device address and protocol data unit, which begins with the function code,
+/// into the end of the byte slice. The frame ends with its longitudinal redundancy check and CRLF. Returns the
+/// indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the frame, which takes `7 + 2 * pdu.len()` bytes, this will panic.
+pub fn format_modbus_ascii(address: u8, pdu: &[u8], string: &mut [u8]) -> usize {
+    format_line(&[address], pdu, string)
+}
+
+#[test]
+fn intel_hex_records() {
+    let mut buffer = [0u8; 48];
+    let i = format_intel_hex(RecordType::EndOfFile, 0, &[], &mut buffer);
+    assert_eq!(&buffer[i..], b":00000001FF\r\n");
+
+    let i = format_intel_hex(RecordType::ExtendedLinearAddress, 0, &[0x08, 0x00], &mut buffer);
+    assert_eq!(&buffer[i..], b":020000040800F2\r\n");
+
+    let data = [0x21, 0x46, 0x01, 0x36, 0x01, 0x21, 0x47, 0x01, 0x36, 0x00, 0x7E, 0xFE, 0x09, 0xD2, 0x19, 0x01];
+    let i = format_intel_hex(RecordType::Data, 0x0100, &data, &mut buffer);
+    assert_eq!(&buffer[i..], b":10010000214601360121470136007EFE09D2190140\r\n");
+}
+
+#[test]
+fn modbus_ascii_frames() {
+    let mut buffer = [0u8; 24];
+    let i = format_modbus_ascii(0x01, &[0x06, 0x00, 0x01, 0x00, 0x03], &mut buffer);
+    assert_eq!(&buffer[i..], b":010600010003F5\r\n");
+}
@@ -0,0 +1,117 @@
+//! The numeric parts of HTTP/1.1 messages: the `Content-Length` header, the size lines of chunked transfers, and
+//! the dates of the `Date` and `Last-Modified` headers.
+//!
+//! ```
+//! use numtoa::http::{format_chunk_size, format_content_length};
+//!
+//! let mut buffer = [0u8; 40];
+//! let start = format_content_length(12345, &mut buffer);
+//! assert_eq!(&buffer[start..], b"Content-Length: 12345\r\n");
+//!
+//! let start = format_chunk_size(4096, &mut buffer);
+//! assert_eq!(&buffer[start..], b"1000\r\n");
+//! ```
+
+use super::datetime::{write_named_date, write_time, DateTime, OutOfRange, YearRange};
+use super::rtl::Rtl;
+
+/// Writes a `Content-Length` header line for a body of `len` bytes, ending with CRLF, into the end of the byte
+/// slice. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, which is at most 38 bytes, this will panic.
+pub fn format_content_length(len: u64, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(b"\r\n");
+    rtl.digits(u128::from(len));
+    rtl.bytes(b"Content-Length: ");
+    rtl.index()
+}
+
+/// Writes the size line of a chunk of `len` bytes in a chunked transfer, which is the size in hexadecimal ending
+/// with CRLF, into the end of the byte slice. The last chunk of a body has a size of zero, and is followed by an
+/// empty line. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_chunk_size(len: usize, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(b"\r\n");
+    rtl.hex(len as u128);
+    rtl.index()
+}
+
+/// Writes the Unix timestamp into the end of the byte slice as an HTTP-date in the preferred format of RFC 7231,
+/// such as `Sun, 06 Nov 1994 08:49:37 GMT`. Returns the indice where the inner string begins.
+///
+/// Every timestamp is written, including those whose years fall outside of 0000 through 9999, which are written
+/// with a sign or more than four digits that an HTTP-date does not allow. Use `try_format_http_date` with
+/// `YearRange::FourDigit` to reject them instead.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, which is 29 bytes for the years 0 through 9999, this
+/// will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::http::format_http_date;
+///
+/// let mut buffer = [0u8; 29];
+/// let start = format_http_date(784_111_777, &mut buffer);
+/// assert_eq!(&buffer[start..], b"Sun, 06 Nov 1994 08:49:37 GMT");
+/// ```
+pub fn format_http_date(epoch_secs: i64, string: &mut [u8]) -> usize {
+    try_format_http_date(epoch_secs, YearRange::Proleptic, string).expect("numtoa: every year is accepted")
+}
+
+/// Writes the Unix timestamp as `format_http_date` does, but only when its year is within the accepted range.
+///
+/// # Errors
+/// Returns `OutOfRange` when the year of the timestamp is outside of the range, without writing anything.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::datetime::{OutOfRange, YearRange};
+/// use numtoa::http::try_format_http_date;
+///
+/// let mut buffer = [0u8; 29];
+/// assert_eq!(try_format_http_date(-62_167_219_201, YearRange::FourDigit, &mut buffer), Err(OutOfRange));
+/// ```
+pub fn try_format_http_date(epoch_secs: i64, years: YearRange, string: &mut [u8]) -> Result<usize, OutOfRange> {
+    let time = DateTime::try_from_unix(epoch_secs, years)?;
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(b" GMT");
+    write_time(&mut rtl, &time);
+    rtl.byte(b' ');
+    write_named_date(&mut rtl, &time);
+    Ok(rtl.index())
+}
+
+#[test]
+fn http_dates() {
+    let mut buffer = [0u8; 29];
+    let i = format_http_date(0, &mut buffer);
+    assert_eq!(&buffer[i..], b"Thu, 01 Jan 1970 00:00:00 GMT");
+
+    let i = format_http_date(253_402_300_799, &mut buffer);
+    assert_eq!(&buffer[i..], b"Fri, 31 Dec 9999 23:59:59 GMT");
+
+    assert_eq!(try_format_http_date(253_402_300_800, YearRange::FourDigit, &mut buffer), Err(OutOfRange));
+    assert_eq!(try_format_http_date(-62_167_219_200, YearRange::FourDigit, &mut buffer), Ok(0));
+}
+
+#[test]
+fn http_lines() {
+    let mut buffer = [0u8; 38];
+    let i = format_content_length(u64::MAX, &mut buffer);
+    assert_eq!(&buffer[i..], b"Content-Length: 18446744073709551615\r\n");
+
+    let i = format_chunk_size(0, &mut buffer);
+    assert_eq!(&buffer[i..], b"0\r\n");
+
+    let i = format_chunk_size(0x1A2B, &mut buffer);
+    assert_eq!(&buffer[i..], b"1A2B\r\n");
+}
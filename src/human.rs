@@ -0,0 +1,472 @@
+//! Human readable formatting of quantities, which picks the SI prefix or the word that keeps the number short.
+//!
+//! ```
+//! use numtoa::human::format_freq;
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_freq(16_368_000, 3, &mut buffer);
+//! assert_eq!(&buffer[start..], b"16.368 MHz");
+//! ```
+
+use super::fixed::Rounding;
+use super::rtl::Rtl;
+
+// Picks the largest prefix that keeps the integer part of the value above zero, where each prefix is `step`
+// times larger than the last, and the first prefix applies to the value as given. Values which are not scaled
+// by a prefix have no decimals, and the decimals of the others are capped at what a `u128` holds once scaled. When
+// rounding carries the value up to the next step, the next prefix is used instead. Returns the rounded magnitude,
+// its number of decimals, and the index of the prefix.
+fn pick_prefix(value: u128, step: u128, decimals: u32, prefixes: usize, rounding: Rounding) -> (u128, u32, usize) {
+    let mut divisor = 1u128;
+    let mut prefix = 0;
+    while prefix + 1 < prefixes && divisor.checked_mul(step).is_some_and(|next| value >= next) {
+        divisor *= step;
+        prefix += 1;
+    }
+
+    let mut precision = if prefix == 0 { 0 } else { capped_precision(value, decimals) };
+    let mut magnitude = rounding.div(value * 10u128.pow(precision), divisor);
+    let carried = 10u128.checked_pow(precision)
+        .and_then(|one| step.checked_mul(one))
+        .is_some_and(|limit| magnitude >= limit);
+    if prefix + 1 < prefixes && carried {
+        divisor *= step;
+        prefix += 1;
+        precision = capped_precision(value, decimals);
+        magnitude = rounding.div(value * 10u128.pow(precision), divisor);
+    }
+    (magnitude, precision, prefix)
+}
+
+// The largest number of decimals, up to `decimals`, with which the value still fits in a `u128` once scaled.
+fn capped_precision(value: u128, decimals: u32) -> u32 {
+    (0..=decimals.min(38))
+        .rev()
+        .find(|&precision| 10u128.checked_pow(precision).and_then(|one| value.checked_mul(one)).is_some())
+        .unwrap_or(0)
+}
+
+// Drops the trailing zeros of the fractional digits, along with the decimal point when none are left.
+fn trim_zeros(mut magnitude: u128, mut precision: u32) -> (u128, u32) {
+    while precision > 0 && magnitude.is_multiple_of(10) {
+        magnitude /= 10;
+        precision -= 1;
+    }
+    (magnitude, precision)
+}
+
+// Writes the value using the largest SI prefix that keeps it short, as `pick_prefix` picks it, followed by a space, the
+// prefix, and the unit.
+pub(crate) fn write_si(rtl: &mut Rtl, value: u128, step: u128, decimals: u32, prefixes: &[&[u8]], unit: &[u8]) {
+    let (magnitude, precision, prefix) = pick_prefix(value, step, decimals, prefixes.len(), Rounding::HalfUp);
+    rtl.bytes(unit);
+    rtl.bytes(prefixes[prefix]);
+    rtl.byte(b' ');
+    rtl.fixed(magnitude, precision);
+}
+
+/// Writes a frequency into the end of the byte slice, using whichever of `Hz`, `kHz`, `MHz`, or `GHz` keeps the
+/// number short, with `decimals` fractional digits for the scaled units. Returns the indice where the inner
+/// string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_freq(hz: u64, decimals: u32, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    write_si(&mut rtl, u128::from(hz), 1000, decimals, &[b"", b"k", b"M", b"G"], b"Hz");
+    rtl.index()
+}
+
+/// Writes a bit rate into the end of the byte slice as network equipment reports it, using whichever of `bps`,
+/// `kbps`, `Mbps`, `Gbps`, or `Tbps` keeps the number short. The prefixes are decimal, each a thousand times the
+/// last, rather than the binary prefixes of storage sizes. Scaled units have `decimals` fractional digits.
+/// Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::human::format_bitrate;
+///
+/// let mut buffer = [0u8; 24];
+/// let start = format_bitrate(1_544_000, 2, &mut buffer);
+/// assert_eq!(&buffer[start..], b"1.54 Mbps");
+/// ```
+pub fn format_bitrate(bps: u64, decimals: u32, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    write_si(&mut rtl, u128::from(bps), 1000, decimals, &[b"", b"k", b"M", b"G", b"T"], b"bps");
+    rtl.index()
+}
+
+/// Writes the rate at which `count` events occurred over `elapsed_ticks` of a clock running at `tick_hz` into the
+/// end of the byte slice, as events per second with the SI prefix that keeps the number short, such as `1.23 M/s`.
+/// Rates below a thousand per second are written as whole numbers without a prefix or a space, such as `999/s`.
+/// Rates so large that `decimals` fractional digits would overflow a `u128` are written with as many as fit. Only
+/// integer arithmetic is used. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If no ticks have elapsed, or the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::human::format_rate;
+///
+/// // 5,000,000 operations over 4,056,000 cycles of a 1 MHz timer.
+/// let mut buffer = [0u8; 24];
+/// let start = format_rate(5_000_000, 4_056_000, 1_000_000, 2, &mut buffer);
+/// assert_eq!(&buffer[start..], b"1.23 M/s");
+/// ```
+pub fn format_rate(count: u64, elapsed_ticks: u64, tick_hz: u64, decimals: u32, string: &mut [u8]) -> usize {
+    const PREFIXES: [&[u8]; 7] = [b"", b"k", b"M", b"G", b"T", b"P", b"E"];
+    assert!(elapsed_ticks != 0, "numtoa: no time has elapsed");
+    let per_second = Rounding::HalfUp.div(u128::from(count) * u128::from(tick_hz), u128::from(elapsed_ticks));
+    let (magnitude, precision, prefix) = pick_prefix(per_second, 1000, decimals, PREFIXES.len(), Rounding::HalfUp);
+
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(b"/s");
+    if prefix != 0 {
+        rtl.bytes(PREFIXES[prefix]);
+        rtl.byte(b' ');
+    }
+    rtl.fixed(magnitude, precision);
+    rtl.index()
+}
+
+/// How an exact power of ten is written by `format_power_of_ten`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PowerStyle {
+    /// Writes the power with a caret, such as `10^9`.
+    #[default]
+    Caret,
+    /// Writes the power in E notation, such as `1e9`.
+    E,
+}
+
+/// Writes the value into the end of the byte slice, rendering it as a power of ten, such as `10^9` or `1e9`, when
+/// it is exactly ten raised to an exponent of at least `min_exponent`. Every other value is written as its
+/// decimal digits. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::human::{format_power_of_ten, PowerStyle};
+///
+/// let mut buffer = [0u8; 40];
+/// let start = format_power_of_ten(1_000_000_000, 3, PowerStyle::Caret, &mut buffer);
+/// assert_eq!(&buffer[start..], b"10^9");
+///
+/// let start = format_power_of_ten(1_000_000_001, 3, PowerStyle::E, &mut buffer);
+/// assert_eq!(&buffer[start..], b"1000000001");
+/// ```
+pub fn format_power_of_ten(value: u128, min_exponent: u32, style: PowerStyle, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    let exponent = (0..=38).find(|&exponent| 10u128.pow(exponent) == value);
+    match exponent {
+        Some(exponent) if exponent >= min_exponent => {
+            rtl.digits(u128::from(exponent));
+            rtl.bytes(match style {
+                PowerStyle::Caret => b"10^",
+                PowerStyle::E     => b"1e",
+            });
+        }
+        _ => rtl.digits(value),
+    }
+    rtl.index()
+}
+
+/// How the exponent of an exact square or cube is written by `format_square_or_cube`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SquareStyle {
+    /// Writes the exponent with a caret, such as `1024^2`.
+    #[default]
+    Caret,
+    /// Writes the exponent as a superscript digit, such as `1024²`.
+    Superscript,
+}
+
+// The root of the value when it is exactly the root raised to the exponent, found by bisection.
+fn exact_root(value: u128, exponent: u32) -> Option<u128> {
+    let (mut low, mut high) = (0u128, 1u128 << (128 / exponent + 1));
+    while low < high {
+        let middle = low + (high - low) / 2;
+        match middle.checked_pow(exponent) {
+            Some(power) if power < value => low = middle + 1,
+            _ => high = middle,
+        }
+    }
+    if low.checked_pow(exponent) == Some(value) { Some(low) } else { None }
+}
+
+/// Writes the value into the end of the byte slice, rendering it as a square or a cube, such as `1024^2` or
+/// `1024²`, when it is exactly the square or the cube of a root of at least `min_root`. A value which is both is
+/// written as the cube, which has the shorter root. Every other value is written as its decimal digits. Returns
+/// the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::human::{format_square_or_cube, SquareStyle};
+///
+/// let mut buffer = [0u8; 40];
+/// let start = format_square_or_cube(1_048_576, 16, SquareStyle::Caret, &mut buffer);
+/// assert_eq!(&buffer[start..], b"1024^2");
+///
+/// let start = format_square_or_cube(27_000, 16, SquareStyle::Superscript, &mut buffer);
+/// assert_eq!(&buffer[start..], "30³".as_bytes());
+/// ```
+pub fn format_square_or_cube(value: u128, min_root: u128, style: SquareStyle, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    let power = [3, 2].iter().find_map(|&exponent| {
+        exact_root(value, exponent).filter(|&root| root >= min_root).map(|root| (root, exponent))
+    });
+
+    match power {
+        Some((root, exponent)) => {
+            match (style, exponent) {
+                (SquareStyle::Caret, _)       => rtl.bytes(if exponent == 3 { b"^3" } else { b"^2" }),
+                (SquareStyle::Superscript, 3) => rtl.char('³'),
+                (SquareStyle::Superscript, _) => rtl.char('²'),
+            }
+            rtl.digits(root);
+        }
+        None => rtl.digits(value),
+    }
+    rtl.index()
+}
+
+/// The names given to the powers of a thousand by `format_count_words`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NumberScale {
+    /// The short scale of modern English, in which each name is a thousand times the last, such as a billion
+    /// being a thousand million.
+    #[default]
+    Short,
+    /// The long scale of traditional British English, in which each name is a million times the last, such as a
+    /// billion being a million million, with the powers between them counted in thousands.
+    Long,
+}
+
+impl NumberScale {
+    // The names of the powers of a thousand, which reach past the largest `u64`.
+    fn names(self) -> &'static [&'static [u8]; 7] {
+        match self {
+            NumberScale::Short => &[
+                b"", b"thousand", b"million", b"billion", b"trillion", b"quadrillion", b"quintillion",
+            ],
+            NumberScale::Long  => &[
+                b"", b"thousand", b"million", b"thousand million", b"billion", b"thousand billion", b"trillion",
+            ],
+        }
+    }
+}
+
+/// Writes a count into the end of the byte slice with the English word for its power of a thousand, such as
+/// `1.2 thousand` or `3 million`, as consumer facing text prefers to `1.2M`. The count is rounded half up to at
+/// most `decimals` fractional digits, without trailing zeros, and counts below a thousand are written whole.
+/// Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::human::{format_count_words, NumberScale};
+///
+/// let mut buffer = [0u8; 32];
+/// let start = format_count_words(1_234, 1, NumberScale::Short, &mut buffer);
+/// assert_eq!(&buffer[start..], b"1.2 thousand");
+///
+/// let start = format_count_words(3_000_000_000, 1, NumberScale::Long, &mut buffer);
+/// assert_eq!(&buffer[start..], b"3 thousand million");
+/// ```
+pub fn format_count_words(count: u64, decimals: u32, scale: NumberScale, string: &mut [u8]) -> usize {
+    let names = scale.names();
+    let (magnitude, precision, name) = pick_prefix(u128::from(count), 1000, decimals, names.len(), Rounding::HalfUp);
+    let (magnitude, precision) = trim_zeros(magnitude, precision);
+
+    let mut rtl = Rtl::new(string);
+    if name != 0 {
+        rtl.bytes(names[name]);
+        rtl.byte(b' ');
+    }
+    rtl.fixed(magnitude, precision);
+    rtl.index()
+}
+
+/// Writes a count into the end of the byte slice in the compact notation of social media and dashboards, with
+/// `K`, `M`, `B`, or `T` for thousands, millions, billions, and trillions, such as `1.2K` or `-3.4M`. Unlike the
+/// SI prefixes, the letter follows the number without a space, and the count has at most one fractional digit,
+/// which is dropped when it is zero. Counts below a thousand are written whole. Returns the indice where the
+/// inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::fixed::Rounding;
+/// use numtoa::human::format_compact;
+///
+/// let mut buffer = [0u8; 24];
+/// let start = format_compact(1_290, Rounding::Truncate, &mut buffer);
+/// assert_eq!(&buffer[start..], b"1.2K");
+///
+/// let start = format_compact(-5_600_000_000, Rounding::HalfUp, &mut buffer);
+/// assert_eq!(&buffer[start..], b"-5.6B");
+/// ```
+pub fn format_compact(count: i64, rounding: Rounding, string: &mut [u8]) -> usize {
+    const SUFFIXES: [&[u8]; 5] = [b"", b"K", b"M", b"B", b"T"];
+    let (magnitude, precision, suffix) =
+        pick_prefix(u128::from(count.unsigned_abs()), 1000, 1, SUFFIXES.len(), rounding);
+    let (magnitude, precision) = trim_zeros(magnitude, precision);
+
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(SUFFIXES[suffix]);
+    rtl.fixed(magnitude, precision);
+    if count < 0 && magnitude != 0 {
+        rtl.byte(b'-');
+    }
+    rtl.index()
+}
+
+#[test]
+fn frequencies() {
+    let mut buffer = [0u8; 32];
+    let i = format_freq(999, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"999 Hz");
+
+    let i = format_freq(32_768, 3, &mut buffer);
+    assert_eq!(&buffer[i..], b"32.768 kHz");
+
+    let i = format_freq(999_999, 1, &mut buffer);
+    assert_eq!(&buffer[i..], b"1.0 MHz");
+
+    let i = format_freq(2_400_000_000, 0, &mut buffer);
+    assert_eq!(&buffer[i..], b"2 GHz");
+
+    let i = format_freq(u64::MAX, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"18446744073.71 GHz");
+}
+
+#[test]
+fn bitrates() {
+    let mut buffer = [0u8; 32];
+    let i = format_bitrate(9_600, 1, &mut buffer);
+    assert_eq!(&buffer[i..], b"9.6 kbps");
+
+    let i = format_bitrate(999, 1, &mut buffer);
+    assert_eq!(&buffer[i..], b"999 bps");
+
+    let i = format_bitrate(10_000_000_000, 0, &mut buffer);
+    assert_eq!(&buffer[i..], b"10 Gbps");
+
+    let i = format_bitrate(u64::MAX, 1, &mut buffer);
+    assert_eq!(&buffer[i..], b"18446744.1 Tbps");
+}
+
+#[test]
+fn rates() {
+    let mut buffer = [0u8; 32];
+    let i = format_rate(0, 1, 1, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"0/s");
+
+    let i = format_rate(999, 1, 1, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"999/s");
+
+    let i = format_rate(1500, 3, 2, 1, &mut buffer);
+    assert_eq!(&buffer[i..], b"1.0 k/s");
+
+    let i = format_rate(48_000, 1_000, 1_000, 1, &mut buffer);
+    assert_eq!(&buffer[i..], b"48.0 k/s");
+
+    let i = format_rate(u64::MAX, 1, u64::MAX, 0, &mut buffer);
+    assert_eq!(&buffer[i..], b"340282366920938463426 E/s");
+
+    let i = format_rate(u64::MAX, 1, 1, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"18.45 E/s");
+
+    // Two decimals would overflow, so the rate keeps none.
+    let i = format_rate(u64::MAX, 1, u64::MAX, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"340282366920938463426 E/s");
+
+    let i = format_rate(u64::MAX, 1, u64::MAX, 40, &mut buffer);
+    assert_eq!(&buffer[i..], b"340282366920938463426 E/s");
+}
+
+#[test]
+fn powers_of_ten() {
+    let mut buffer = [0u8; 40];
+    let i = format_power_of_ten(10u128.pow(38), 0, PowerStyle::E, &mut buffer);
+    assert_eq!(&buffer[i..], b"1e38");
+
+    let i = format_power_of_ten(1, 0, PowerStyle::Caret, &mut buffer);
+    assert_eq!(&buffer[i..], b"10^0");
+
+    let i = format_power_of_ten(100, 3, PowerStyle::Caret, &mut buffer);
+    assert_eq!(&buffer[i..], b"100");
+
+    let i = format_power_of_ten(0, 0, PowerStyle::Caret, &mut buffer);
+    assert_eq!(&buffer[i..], b"0");
+}
+
+#[test]
+fn squares_and_cubes() {
+    let mut buffer = [0u8; 48];
+    let i = format_square_or_cube(64, 2, SquareStyle::Caret, &mut buffer);
+    assert_eq!(&buffer[i..], b"4^3");
+
+    let i = format_square_or_cube(64, 5, SquareStyle::Caret, &mut buffer);
+    assert_eq!(&buffer[i..], b"8^2");
+
+    let i = format_square_or_cube(65, 2, SquareStyle::Caret, &mut buffer);
+    assert_eq!(&buffer[i..], b"65");
+
+    let i = format_square_or_cube(u128::from(u64::MAX).pow(2), 2, SquareStyle::Superscript, &mut buffer);
+    assert_eq!(&buffer[i..], "18446744073709551615²".as_bytes());
+
+    let i = format_square_or_cube(u128::MAX, 2, SquareStyle::Caret, &mut buffer);
+    assert_eq!(&buffer[i..], b"340282366920938463463374607431768211455");
+
+    let i = format_square_or_cube(0, 0, SquareStyle::Caret, &mut buffer);
+    assert_eq!(&buffer[i..], b"0^3");
+}
+
+#[test]
+fn count_words() {
+    let mut buffer = [0u8; 40];
+    let i = format_count_words(999, 1, NumberScale::Short, &mut buffer);
+    assert_eq!(&buffer[i..], b"999");
+
+    let i = format_count_words(999_960, 1, NumberScale::Short, &mut buffer);
+    assert_eq!(&buffer[i..], b"1 million");
+
+    let i = format_count_words(2_500_000_000_000, 2, NumberScale::Long, &mut buffer);
+    assert_eq!(&buffer[i..], b"2.5 billion");
+
+    let i = format_count_words(u64::MAX, 1, NumberScale::Short, &mut buffer);
+    assert_eq!(&buffer[i..], b"18.4 quintillion");
+}
+
+#[test]
+fn compact() {
+    let mut buffer = [0u8; 24];
+    let i = format_compact(999, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"999");
+
+    let i = format_compact(1_000, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"1K");
+
+    let i = format_compact(999_999, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"1M");
+
+    let i = format_compact(999_999, Rounding::Truncate, &mut buffer);
+    assert_eq!(&buffer[i..], b"999.9K");
+
+    let i = format_compact(i64::MIN, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"-9223372T");
+}
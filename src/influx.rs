@@ -0,0 +1,108 @@
+//! The field set of the InfluxDB line protocol, for gateways which batch sensor readings into a fixed buffer.
+//! Each field is written with the suffix of its type, such as `123i` for an integer and `1.5` for a float.
+//!
+//! ```
+//! use numtoa::influx::FieldSet;
+//!
+//! let mut buffer = [0u8; 64];
+//! let mut fields = FieldSet::new(&mut buffer);
+//! fields.integer(b"count", 123).float(b"temp", 215, 1).unsigned(b"uptime", 86400);
+//! assert_eq!(fields.finish(), b"count=123i,temp=21.5,uptime=86400u");
+//! ```
+
+use super::fixed::{write_scaled, Rounding};
+use super::ltr::Ltr;
+
+/// A set of fields which is being written into a byte slice from left to right, separated by commas.
+pub struct FieldSet<'a> {
+    ltr: Ltr<'a>,
+    empty: bool,
+}
+
+impl<'a> FieldSet<'a> {
+    /// Begins an empty field set at the start of the buffer.
+    pub fn new(string: &'a mut [u8]) -> Self {
+        FieldSet { ltr: Ltr::new(string), empty: true }
+    }
+
+    // Writes the separator and the key with its `=`, escaping the key only when it requires it.
+    fn key(&mut self, key: &[u8]) {
+        if !self.empty {
+            self.ltr.bytes(b",");
+        }
+        self.empty = false;
+
+        if key.iter().all(|byte| !matches!(byte, b',' | b'=' | b' ')) {
+            self.ltr.bytes(key);
+        } else {
+            for byte in key {
+                if matches!(byte, b',' | b'=' | b' ') {
+                    self.ltr.bytes(b"\\");
+                }
+                self.ltr.bytes(core::slice::from_ref(byte));
+            }
+        }
+        self.ltr.bytes(b"=");
+    }
+
+    /// Appends a signed integer field, such as `count=-5i`.
+    ///
+    /// # Panics
+    /// If the supplied buffer is too small to hold the field, this will panic, as do the other fields.
+    pub fn integer(&mut self, key: &[u8], value: i64) -> &mut Self {
+        self.key(key);
+        self.ltr.rtl(|rtl| {
+            rtl.byte(b'i');
+            rtl.digits(u128::from(value.unsigned_abs()));
+            if value < 0 {
+                rtl.byte(b'-');
+            }
+        });
+        self
+    }
+
+    /// Appends an unsigned integer field, such as `uptime=86400u`.
+    pub fn unsigned(&mut self, key: &[u8], value: u64) -> &mut Self {
+        self.key(key);
+        self.ltr.rtl(|rtl| {
+            rtl.byte(b'u');
+            rtl.digits(u128::from(value));
+        });
+        self
+    }
+
+    /// Appends a float field from a value with `scale` implied decimal places, such as `temp=21.5` from `215`
+    /// with a scale of one. The value is written exactly, without a suffix, as floats are in the line protocol.
+    pub fn float(&mut self, key: &[u8], value: i64, scale: u32) -> &mut Self {
+        self.key(key);
+        self.ltr.rtl(|rtl| write_scaled(rtl, value, scale, scale, Rounding::Truncate));
+        self
+    }
+
+    /// Returns the written field set.
+    pub fn finish(self) -> &'a [u8] {
+        self.ltr.into_written()
+    }
+}
+
+#[test]
+fn influx_fields() {
+    let mut buffer = [0u8; 64];
+    let mut fields = FieldSet::new(&mut buffer);
+    fields.integer(b"min", i64::MIN).float(b"a b,c=d", -5, 2).float(b"whole", 7, 0);
+    assert_eq!(fields.finish(), b"min=-9223372036854775808i,a\\ b\\,c\\=d=-0.05,whole=7");
+
+    let mut buffer = [0u8; 4];
+    assert_eq!(FieldSet::new(&mut buffer).finish(), b"");
+}
+
+#[test]
+fn influx_long_floats() {
+    let mut buffer = [0u8; 256];
+    let mut fields = FieldSet::new(&mut buffer);
+    fields.float(b"tiny", -1, 200);
+    let written = fields.finish();
+    assert_eq!(written.len(), 5 + 203);
+    assert_eq!(&written[..9], b"tiny=-0.0");
+    assert_eq!(written[written.len() - 1], b'1');
+}
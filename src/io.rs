@@ -0,0 +1,70 @@
+//! Writing numbers directly into the no_std `Write` trait of the `genio` crate. Requires the `genio` feature.
+//!
+//! There is no adapter for the `Write` trait of `core2`, since every release of that crate has been yanked.
+//!
+//! ```
+//! # #[cfg(feature = "i32")] {
+//! use numtoa::io::write_numtoa;
+//!
+//! let mut sink = genio::util::Sink;
+//! write_numtoa(&mut sink, -1234i32, 10).unwrap();
+//! # }
+//! ```
+
+use genio::Write;
+
+use super::rtl::SCRATCH_LEN;
+use super::NumToA;
+
+/// Writes the number in the given base into the writer, hinting the writer with the number of bytes beforehand.
+///
+/// # Errors
+/// Returns the error of the writer if the digits could not all be written.
+///
+/// # Panics
+/// If the number requires more than 129 bytes, which no primitive integer does, this will panic.
+pub fn write_numtoa<W: Write, T: NumToA<B>, B>(writer: &mut W, number: T, base: B) -> Result<(), W::WriteError> {
+    let mut scratch = [0u8; SCRATCH_LEN];
+    let start = number.numtoa(base, &mut scratch);
+    writer.size_hint(SCRATCH_LEN - start);
+    writer.write_all(&scratch[start..])
+}
+
+#[cfg(all(test, feature = "u64"))]
+struct Trickle {
+    buffer: [u8; 16],
+    len: usize,
+}
+
+// Accepts at most two bytes per write, so that the digits must be written in several parts.
+#[cfg(all(test, feature = "u64"))]
+impl Write for Trickle {
+    type WriteError = ();
+    type FlushError = ();
+
+    fn write(&mut self, buf: &[u8]) -> Result<usize, ()> {
+        let count = buf.len().min(2).min(self.buffer.len() - self.len);
+        if count == 0 && !buf.is_empty() {
+            return Err(());
+        }
+        self.buffer[self.len..self.len + count].copy_from_slice(&buf[..count]);
+        self.len += count;
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> Result<(), ()> {
+        Ok(())
+    }
+
+    fn size_hint(&mut self, _bytes: usize) {}
+}
+
+#[test]
+#[cfg(feature = "u64")]
+fn write_in_parts() {
+    let mut writer = Trickle { buffer: [0u8; 16], len: 0 };
+    write_numtoa(&mut writer, 12345u64, 10).unwrap();
+    write_numtoa(&mut writer, 0xBEEFu64, 16).unwrap();
+    assert_eq!(&writer.buffer[..writer.len], b"12345BEEF");
+    assert!(write_numtoa(&mut writer, u64::MAX, 10).is_err());
+}
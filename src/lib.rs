@@ -66,7 +66,98 @@
 //! ```
 
 #![no_std]
-use core::mem::size_of;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod addr;
+pub mod ansi;
+pub mod bar;
+pub mod battery;
+pub mod braille;
+pub mod compose;
+pub mod crc;
+pub mod datetime;
+pub mod dds;
+pub mod delta;
+pub mod diff;
+pub mod digits;
+pub mod dtmf;
+pub mod ean;
+pub mod extend;
+pub mod fixed;
+pub mod frame;
+pub mod geo;
+pub mod glyph;
+pub mod hexline;
+pub mod http;
+pub mod human;
+pub mod influx;
+pub mod limbs;
+pub mod metrics;
+pub mod morse;
+pub mod nmea;
+pub mod option;
+#[cfg(feature = "alloc")]
+pub mod owned;
+pub mod plural;
+pub mod positional;
+pub mod radix;
+pub mod range;
+pub mod ratio;
+pub mod record;
+pub mod resp;
+pub mod sci;
+pub mod segment;
+pub mod storage;
+pub mod table;
+pub mod template;
+pub mod text;
+pub mod time;
+pub mod unicode;
+pub mod units;
+
+mod ltr;
+mod rtl;
+
+#[cfg(feature = "ux")]
+extern crate ux;
+#[cfg(feature = "arbitrary-int")]
+extern crate arbitrary_int;
+
+#[cfg(feature = "num-bigint")]
+extern crate num_bigint;
+#[cfg(feature = "ethnum")]
+extern crate ethnum;
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+#[cfg(feature = "bench-support")]
+extern crate criterion;
+#[cfg(feature = "bench-support")]
+extern crate itoa;
+#[cfg(feature = "genio")]
+extern crate genio;
+#[cfg(feature = "primitive-types")]
+extern crate primitive_types;
+#[cfg(feature = "smallvec")]
+extern crate smallvec;
+#[cfg(feature = "tinyvec")]
+extern crate tinyvec;
+
+#[cfg(any(feature = "ux", feature = "arbitrary-int"))]
+mod narrow;
+
+#[cfg(feature = "bench-support")]
+pub mod bench_support;
+#[cfg(feature = "num-bigint")]
+pub mod bigint;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "genio")]
+pub mod io;
+#[cfg(feature = "selftest")]
+pub mod selftest;
+#[cfg(any(feature = "ethnum", feature = "primitive-types"))]
+mod wide;
 
 /// Converts a number into a string representation, storing the conversion into a mutable byte slice.
 pub trait NumToA<T> {
@@ -96,17 +187,44 @@ pub trait NumToA<T> {
     fn numtoa(self, base: T, string: &mut [u8]) -> usize;
 }
 
-// A lookup table to prevent the need for conditional branching
-// The value of the remainder of each step will be used as the index
-const LOOKUP: &'static [u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-
-// A lookup table optimized for decimal lookups. Each two indices represents one possible number.
-const DEC_LOOKUP: &'static [u8; 200] = b"0001020304050607080910111213141516171819\
-                                         2021222324252627282930313233343536373839\
-                                         4041424344454647484950515253545556575859\
-                                         6061626364656667686970717273747576777879\
-                                         8081828384858687888990919293949596979899";
+/// A lookup table of the upper case digits for every supported base, indexed by the value of the digit.
+/// This prevents the need for conditional branching, as the remainder of each step is used as the index.
+pub const LOOKUP_UPPER: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A lookup table of the lower case digits for every supported base, indexed by the value of the digit.
+pub const LOOKUP_LOWER: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// A lookup table optimized for decimal lookups. Each two indices represents one possible number, so the two
+/// digits of `n` are found at `DEC_LOOKUP[n * 2..n * 2 + 2]`.
+pub const DEC_LOOKUP: &[u8; 200] = b"0001020304050607080910111213141516171819\
+                                     2021222324252627282930313233343536373839\
+                                     4041424344454647484950515253545556575859\
+                                     6061626364656667686970717273747576777879\
+                                     8081828384858687888990919293949596979899";
+
+/// A lookup table of upper case hexadecimal digit pairs. The two digits of the byte `n` are found at
+/// `HEX_LOOKUP_UPPER[n * 2..n * 2 + 2]`.
+pub const HEX_LOOKUP_UPPER: &[u8; 512] = &hex_pairs(LOOKUP_UPPER);
+
+/// A lookup table of lower case hexadecimal digit pairs. The two digits of the byte `n` are found at
+/// `HEX_LOOKUP_LOWER[n * 2..n * 2 + 2]`.
+pub const HEX_LOOKUP_LOWER: &[u8; 512] = &hex_pairs(LOOKUP_LOWER);
+
+const fn hex_pairs(digits: &[u8; 36]) -> [u8; 512] {
+    let mut table = [0u8; 512];
+    let mut byte = 0;
+    while byte < 256 {
+        table[byte * 2] = digits[byte >> 4];
+        table[byte * 2 + 1] = digits[byte & 0xF];
+        byte += 1;
+    }
+    table
+}
 
+#[cfg(any(
+    feature = "i16", feature = "i32", feature = "i64", feature = "i128", feature = "isize",
+    feature = "u16", feature = "u32", feature = "u64", feature = "u128", feature = "usize",
+))]
 macro_rules! base_10 {
     ($number:ident, $index:ident, $string:ident) => {
         // Decode four characters at the same time
@@ -127,227 +245,379 @@ macro_rules! base_10 {
         } else if $number > 99 {
             let section = ($number as u16 / 10) * 2;
             $string[$index-2..$index].copy_from_slice(&DEC_LOOKUP[section as usize..section as usize+2]);
-            $string[$index] = LOOKUP[($number % 10) as usize];
+            $string[$index] = LOOKUP_UPPER[($number % 10) as usize];
             $index = $index.wrapping_sub(3);
         } else if $number > 9 {
-            $number *= 2;
-            $string[$index-1..$index+1].copy_from_slice(&DEC_LOOKUP[$number as usize..$number as usize+2]);
+            let pair = $number as usize * 2;
+            $string[$index-1..$index+1].copy_from_slice(&DEC_LOOKUP[pair..pair+2]);
             $index = $index.wrapping_sub(2);
         } else {
-            $string[$index] = LOOKUP[$number as usize];
+            $string[$index] = LOOKUP_UPPER[$number as usize];
             $index = $index.wrapping_sub(1);
         }
     }
 }
 
 macro_rules! impl_unsized_numtoa_for {
-    ($t:ty) => {
-        impl NumToA<$t> for $t {
-            fn numtoa(mut self, base: $t, string: &mut [u8]) -> usize {
-                // Check if the buffer is large enough and panic on debug builds if it isn't
-                if cfg!(debug_assertions) {
-                    if base == 10 {
-                        match size_of::<$t>() {
-                            2 => debug_assert!(string.len() >= 5,  "u16 base 10 conversions require at least 5 bytes"),
-                            4 => debug_assert!(string.len() >= 10, "u32 base 10 conversions require at least 10 bytes"),
-                            8 => debug_assert!(string.len() >= 20, "u64 base 10 conversions require at least 20 bytes"),
-                            _ => unreachable!()
-                        }
-                    }
-                }
-
-                let mut index = string.len() - 1;
-                if self == 0 {
-                    string[index] = b'0';
-                    return index;
-                }
-
-                if base == 10 {
-                    // Convert using optimized base 10 algorithm
-                    base_10!(self, index, string);
-                } else {
-                    while self != 0 {
-                        let rem = self % base;
-                        string[index] = LOOKUP[rem as usize];
-                        index = index.wrapping_sub(1);
-                        self /= base;
-                    }
-                }
+    ($t:ty, $feature:tt, $write:ident, $write_base10:ident, $min_len:expr) => {
+        /// Writes the number into the end of the byte slice in the given base, returning the indice where the
+        /// inner string begins. This is the non-generic equivalent of `NumToA::numtoa`, for when exactly one
+        /// copy of each routine is wanted in the final binary.
+        ///
+        /// # Panics
+        /// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+        #[cfg(feature = $feature)]
+        #[cfg_attr(feature = "inline-never", inline(never))]
+        pub fn $write(mut number: $t, base: $t, string: &mut [u8]) -> usize {
+            if base == 10 {
+                return $write_base10(number, string);
+            }
 
-                index.wrapping_add(1)
+            let mut index = string.len() - 1;
+            if number == 0 {
+                string[index] = b'0';
+                return index;
             }
-        }
-    }
-}
 
-macro_rules! impl_sized_numtoa_for {
-    ($t:ty) => {
-        impl NumToA<$t> for $t {
-            fn numtoa(mut self, base: $t, string: &mut [u8]) -> usize {
-                if cfg!(debug_assertions) {
-                    if base == 10 {
-                        match size_of::<$t>() {
-                            2 => debug_assert!(string.len() >= 6,  "i16 base 10 conversions require at least 6 bytes"),
-                            4 => debug_assert!(string.len() >= 11, "i32 base 10 conversions require at least 11 bytes"),
-                            8 => debug_assert!(string.len() >= 20, "i64 base 10 conversions require at least 20 bytes"),
-                            _ => unreachable!()
-                        }
-                    }
-                }
-
-                let mut index = string.len() - 1;
-                let mut is_negative = false;
-
-                if self < 0 {
-                    is_negative = true;
-                    self = match self.checked_abs() {
-                        Some(value) => value,
-                        None        => {
-                            let value = <$t>::max_value();
-                            string[index] = LOOKUP[((value % base + 1) % base) as usize];
-                            index -= 1;
-                            value / base + ((value % base == base - 1) as $t)
-                        }
-                    };
-                } else if self == 0 {
-                    string[index] = b'0';
-                    return index;
-                }
-
-                if base == 10 {
-                    // Convert using optimized base 10 algorithm
-                    base_10!(self, index, string);
-                } else {
-                    while self != 0 {
-                        let rem = self % base;
-                        string[index] = LOOKUP[rem as usize];
-                        index = index.wrapping_sub(1);
-                        self /= base;
-                    }
-                }
+            while number != 0 {
+                let rem = number % base;
+                string[index] = LOOKUP_UPPER[rem as usize];
+                index = index.wrapping_sub(1);
+                number /= base;
+            }
 
-                if is_negative {
-                    string[index] = b'-';
-                    index = index.wrapping_sub(1);
-                }
+            index.wrapping_add(1)
+        }
 
-                index.wrapping_add(1)
+        /// Writes the number into the end of the byte slice in base 10, returning the indice where the inner
+        /// string begins. Only the optimized base 10 algorithm is compiled into this function.
+        ///
+        /// # Panics
+        /// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+        /// On debug builds, this function will perform a check to ensure that the input array is large enough to
+        /// hold the largest possible value in digits.
+        #[cfg(feature = $feature)]
+        #[cfg_attr(feature = "inline-never", inline(never))]
+        pub fn $write_base10(mut number: $t, string: &mut [u8]) -> usize {
+            // Check if the buffer is large enough and panic on debug builds if it isn't
+            debug_assert!(string.len() >= $min_len, concat!(stringify!($t), " base 10 conversions require at least ",
+                stringify!($min_len), " bytes"));
+
+            let mut index = string.len() - 1;
+            if number == 0 {
+                string[index] = b'0';
+                return index;
             }
+
+            base_10!(number, index, string);
+            index.wrapping_add(1)
         }
 
+        #[cfg(feature = $feature)]
+        impl NumToA<$t> for $t {
+            fn numtoa(self, base: $t, string: &mut [u8]) -> usize {
+                $write(self, base, string)
+            }
+        }
     }
 }
 
-impl_sized_numtoa_for!(i16);
-impl_sized_numtoa_for!(i32);
-impl_sized_numtoa_for!(i64);
-impl_sized_numtoa_for!(isize);
-impl_unsized_numtoa_for!(u16);
-impl_unsized_numtoa_for!(u32);
-impl_unsized_numtoa_for!(u64);
-impl_unsized_numtoa_for!(usize);
-
-impl NumToA<i8> for i8 {
-    fn numtoa(mut self, base: i8, string: &mut [u8]) -> usize {
-        if cfg!(debug_assertions) {
+macro_rules! impl_sized_numtoa_for {
+    ($t:ty, $feature:tt, $write:ident, $write_base10:ident, $min_len:expr) => {
+        /// Writes the number into the end of the byte slice in the given base, returning the indice where the
+        /// inner string begins. This is the non-generic equivalent of `NumToA::numtoa`, for when exactly one
+        /// copy of each routine is wanted in the final binary.
+        ///
+        /// # Panics
+        /// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+        #[cfg(feature = $feature)]
+        #[cfg_attr(feature = "inline-never", inline(never))]
+        pub fn $write(mut number: $t, base: $t, string: &mut [u8]) -> usize {
             if base == 10 {
-                debug_assert!(string.len() >= 4, "i8 conversions need at least 4 bytes");
+                return $write_base10(number, string);
             }
-        }
 
-        let mut index = string.len() - 1;
-        let mut is_negative = false;
-
-        if self < 0 {
-            is_negative = true;
-            self = match self.checked_abs() {
-                Some(value) => value,
-                None        => {
-                    let value = <i8>::max_value();
-                    string[index] = LOOKUP[((value % base + 1) % base) as usize];
-                    index -= 1;
-                    value / base + ((value % base == base - 1) as i8)
-                }
-            };
-        } else if self == 0 {
-            string[index] = b'0';
-            return index;
-        }
+            let mut index = string.len() - 1;
+            let mut is_negative = false;
+
+            if number < 0 {
+                is_negative = true;
+                number = match number.checked_abs() {
+                    Some(value) => value,
+                    None        => {
+                        let value = <$t>::MAX;
+                        string[index] = LOOKUP_UPPER[((value % base + 1) % base) as usize];
+                        index -= 1;
+                        value / base + ((value % base == base - 1) as $t)
+                    }
+                };
+            } else if number == 0 {
+                string[index] = b'0';
+                return index;
+            }
 
-        if base == 10 {
-            if self > 99 {
-                let section = (self / 10) * 2;
-                string[index-2..index].copy_from_slice(&DEC_LOOKUP[section as usize..section as usize+2]);
-                string[index] = LOOKUP[(self % 10) as usize];
-                index = index.wrapping_sub(3);
-            } else if self > 9 {
-                self *= 2;
-                string[index-1..index+1].copy_from_slice(&DEC_LOOKUP[self as usize..self as usize+2]);
-                index = index.wrapping_sub(2);
-            } else {
-                string[index] = LOOKUP[self as usize];
+            while number != 0 {
+                let rem = number % base;
+                string[index] = LOOKUP_UPPER[rem as usize];
                 index = index.wrapping_sub(1);
+                number /= base;
             }
-        } else {
-            while self != 0 {
-                let rem = self % base;
-                string[index] = LOOKUP[rem as usize];
+
+            if is_negative {
+                string[index] = b'-';
                 index = index.wrapping_sub(1);
-                self /= base;
             }
+
+            index.wrapping_add(1)
         }
 
-        if is_negative {
-            string[index] = b'-';
-            index = index.wrapping_sub(1);
+        /// Writes the number into the end of the byte slice in base 10, returning the indice where the inner
+        /// string begins. Only the optimized base 10 algorithm is compiled into this function.
+        ///
+        /// # Panics
+        /// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+        /// On debug builds, this function will perform a check to ensure that the input array is large enough to
+        /// hold the largest possible value in digits.
+        #[cfg(feature = $feature)]
+        #[cfg_attr(feature = "inline-never", inline(never))]
+        pub fn $write_base10(mut number: $t, string: &mut [u8]) -> usize {
+            debug_assert!(string.len() >= $min_len, concat!(stringify!($t), " base 10 conversions require at least ",
+                stringify!($min_len), " bytes"));
+
+            let mut index = string.len() - 1;
+            let mut is_negative = false;
+
+            if number < 0 {
+                is_negative = true;
+                number = match number.checked_abs() {
+                    Some(value) => value,
+                    None        => {
+                        // The magnitude of the minimum value of every signed type ends with an 8
+                        string[index] = b'8';
+                        index -= 1;
+                        <$t>::MAX / 10
+                    }
+                };
+            } else if number == 0 {
+                string[index] = b'0';
+                return index;
+            }
+
+            base_10!(number, index, string);
+
+            if is_negative {
+                string[index] = b'-';
+                index = index.wrapping_sub(1);
+            }
+
+            index.wrapping_add(1)
         }
 
-        index.wrapping_add(1)
+        #[cfg(feature = $feature)]
+        impl NumToA<$t> for $t {
+            fn numtoa(self, base: $t, string: &mut [u8]) -> usize {
+                $write(self, base, string)
+            }
+        }
     }
 }
 
-impl NumToA<u8> for u8 {
-    fn numtoa(mut self, base: u8, string: &mut [u8]) -> usize {
-        if cfg!(debug_assertions) {
-            if base == 10 {
-                debug_assert!(string.len() >= 3, "u8 conversions need at least 3 bytes");
-            }
+// The 8-bit types only ever need up to three digits, so they have their own base 10 conversion.
+#[cfg(any(feature = "i8", feature = "u8"))]
+macro_rules! base_10_u8 {
+    ($number:ident, $index:ident, $string:ident) => {
+        if $number > 99 {
+            let section = ($number / 10) * 2;
+            $string[$index-2..$index].copy_from_slice(&DEC_LOOKUP[section as usize..section as usize+2]);
+            $string[$index] = LOOKUP_UPPER[($number % 10) as usize];
+            $index = $index.wrapping_sub(3);
+        } else if $number > 9 {
+            // Doubled as a `usize`, since doubling an `i8` above 63 would overflow.
+            let pair = $number as usize * 2;
+            $string[$index-1..$index+1].copy_from_slice(&DEC_LOOKUP[pair..pair+2]);
+            $index = $index.wrapping_sub(2);
+        } else {
+            $string[$index] = LOOKUP_UPPER[$number as usize];
+            $index = $index.wrapping_sub(1);
         }
+    }
+}
 
-        let mut index = string.len() - 1;
-        if self == 0 {
-            string[index] = b'0';
-            return index;
-        }
+impl_sized_numtoa_for!(i16, "i16", write_i16, write_i16_base10, 6);
+impl_sized_numtoa_for!(i32, "i32", write_i32, write_i32_base10, 11);
+impl_sized_numtoa_for!(i64, "i64", write_i64, write_i64_base10, 20);
+impl_sized_numtoa_for!(i128, "i128", write_i128, write_i128_base10, 40);
+#[cfg(target_pointer_width = "16")]
+impl_sized_numtoa_for!(isize, "isize", write_isize, write_isize_base10, 6);
+#[cfg(target_pointer_width = "32")]
+impl_sized_numtoa_for!(isize, "isize", write_isize, write_isize_base10, 11);
+#[cfg(target_pointer_width = "64")]
+impl_sized_numtoa_for!(isize, "isize", write_isize, write_isize_base10, 20);
+impl_unsized_numtoa_for!(u16, "u16", write_u16, write_u16_base10, 5);
+impl_unsized_numtoa_for!(u32, "u32", write_u32, write_u32_base10, 10);
+impl_unsized_numtoa_for!(u64, "u64", write_u64, write_u64_base10, 20);
+impl_unsized_numtoa_for!(u128, "u128", write_u128, write_u128_base10, 39);
+#[cfg(target_pointer_width = "16")]
+impl_unsized_numtoa_for!(usize, "usize", write_usize, write_usize_base10, 5);
+#[cfg(target_pointer_width = "32")]
+impl_unsized_numtoa_for!(usize, "usize", write_usize, write_usize_base10, 10);
+#[cfg(target_pointer_width = "64")]
+impl_unsized_numtoa_for!(usize, "usize", write_usize, write_usize_base10, 20);
+
+/// Writes the number into the end of the byte slice in the given base, returning the indice where the inner
+/// string begins. This is the non-generic equivalent of `NumToA::numtoa`, for when exactly one copy of each
+/// routine is wanted in the final binary.
+///
+/// # Panics
+/// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+#[cfg(feature = "i8")]
+#[cfg_attr(feature = "inline-never", inline(never))]
+pub fn write_i8(mut number: i8, base: i8, string: &mut [u8]) -> usize {
+    if base == 10 {
+        return write_i8_base10(number, string);
+    }
 
-        if base == 10 {
-            if self > 99 {
-                let section = (self / 10) * 2;
-                string[index-2..index].copy_from_slice(&DEC_LOOKUP[section as usize..section as usize+2]);
-                string[index] = LOOKUP[(self % 10) as usize];
-                index = index.wrapping_sub(3);
-            } else if self > 9 {
-                self *= 2;
-                string[index-1..index+1].copy_from_slice(&DEC_LOOKUP[self as usize..self as usize+2]);
-                index = index.wrapping_sub(2);
-            } else {
-                string[index] = LOOKUP[self as usize];
-                index = index.wrapping_sub(1);
+    let mut index = string.len() - 1;
+    let mut is_negative = false;
+
+    if number < 0 {
+        is_negative = true;
+        number = match number.checked_abs() {
+            Some(value) => value,
+            None        => {
+                let value = i8::MAX;
+                string[index] = LOOKUP_UPPER[((value % base + 1) % base) as usize];
+                index -= 1;
+                value / base + ((value % base == base - 1) as i8)
             }
-        } else {
-            while self != 0 {
-                let rem = self % base;
-                string[index] = LOOKUP[rem as usize];
-                index = index.wrapping_sub(1);
-                self /= base;
+        };
+    } else if number == 0 {
+        string[index] = b'0';
+        return index;
+    }
+
+    while number != 0 {
+        let rem = number % base;
+        string[index] = LOOKUP_UPPER[rem as usize];
+        index = index.wrapping_sub(1);
+        number /= base;
+    }
+
+    if is_negative {
+        string[index] = b'-';
+        index = index.wrapping_sub(1);
+    }
+
+    index.wrapping_add(1)
+}
+
+/// Writes the number into the end of the byte slice in base 10, returning the indice where the inner string
+/// begins. Only the base 10 algorithm is compiled into this function.
+///
+/// # Panics
+/// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+/// On debug builds, this function will perform a check to ensure that the input array is large enough to hold
+/// the largest possible value in digits.
+#[cfg(feature = "i8")]
+#[cfg_attr(feature = "inline-never", inline(never))]
+pub fn write_i8_base10(mut number: i8, string: &mut [u8]) -> usize {
+    debug_assert!(string.len() >= 4, "i8 conversions need at least 4 bytes");
+
+    let mut index = string.len() - 1;
+    let mut is_negative = false;
+
+    if number < 0 {
+        is_negative = true;
+        number = match number.checked_abs() {
+            Some(value) => value,
+            None        => {
+                string[index] = b'8';
+                index -= 1;
+                i8::MAX / 10
             }
-        }
+        };
+    } else if number == 0 {
+        string[index] = b'0';
+        return index;
+    }
+
+    base_10_u8!(number, index, string);
+
+    if is_negative {
+        string[index] = b'-';
+        index = index.wrapping_sub(1);
+    }
+
+    index.wrapping_add(1)
+}
+
+/// Writes the number into the end of the byte slice in the given base, returning the indice where the inner
+/// string begins. This is the non-generic equivalent of `NumToA::numtoa`, for when exactly one copy of each
+/// routine is wanted in the final binary.
+///
+/// # Panics
+/// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+#[cfg(feature = "u8")]
+#[cfg_attr(feature = "inline-never", inline(never))]
+pub fn write_u8(mut number: u8, base: u8, string: &mut [u8]) -> usize {
+    if base == 10 {
+        return write_u8_base10(number, string);
+    }
+
+    let mut index = string.len() - 1;
+    if number == 0 {
+        string[index] = b'0';
+        return index;
+    }
+
+    while number != 0 {
+        let rem = number % base;
+        string[index] = LOOKUP_UPPER[rem as usize];
+        index = index.wrapping_sub(1);
+        number /= base;
+    }
+
+    index.wrapping_add(1)
+}
+
+/// Writes the number into the end of the byte slice in base 10, returning the indice where the inner string
+/// begins. Only the base 10 algorithm is compiled into this function.
+///
+/// # Panics
+/// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+/// On debug builds, this function will perform a check to ensure that the input array is large enough to hold
+/// the largest possible value in digits.
+#[cfg(feature = "u8")]
+#[cfg_attr(feature = "inline-never", inline(never))]
+pub fn write_u8_base10(number: u8, string: &mut [u8]) -> usize {
+    debug_assert!(string.len() >= 3, "u8 conversions need at least 3 bytes");
+
+    let mut index = string.len() - 1;
+    if number == 0 {
+        string[index] = b'0';
+        return index;
+    }
+
+    base_10_u8!(number, index, string);
+    index.wrapping_add(1)
+}
+
+#[cfg(feature = "i8")]
+impl NumToA<i8> for i8 {
+    fn numtoa(self, base: i8, string: &mut [u8]) -> usize {
+        write_i8(self, base, string)
+    }
+}
 
-        index.wrapping_add(1)
+#[cfg(feature = "u8")]
+impl NumToA<u8> for u8 {
+    fn numtoa(self, base: u8, string: &mut [u8]) -> usize {
+        write_u8(self, base, string)
     }
 }
 
 #[test]
+#[cfg(feature = "u8")]
 #[should_panic]
 fn base10_u8_array_too_small() {
     let mut buffer = [0u8; 2];
@@ -355,12 +625,14 @@ fn base10_u8_array_too_small() {
 }
 
 #[test]
+#[cfg(feature = "u8")]
 fn base10_u8_array_just_right() {
     let mut buffer = [0u8; 3];
     let _ = 0u8.numtoa(10, &mut buffer);
 }
 
 #[test]
+#[cfg(feature = "i8")]
 #[should_panic]
 fn base10_i8_array_too_small() {
     let mut buffer = [0u8; 3];
@@ -368,6 +640,7 @@ fn base10_i8_array_too_small() {
 }
 
 #[test]
+#[cfg(feature = "i8")]
 fn base10_i8_array_just_right() {
     let mut buffer = [0u8; 4];
     let i = (-127i8).numtoa(10, &mut buffer);
@@ -375,6 +648,7 @@ fn base10_i8_array_just_right() {
 }
 
 #[test]
+#[cfg(feature = "i16")]
 #[should_panic]
 fn base10_i16_array_too_small() {
     let mut buffer = [0u8; 5];
@@ -382,6 +656,7 @@ fn base10_i16_array_too_small() {
 }
 
 #[test]
+#[cfg(feature = "i16")]
 fn base10_i16_array_just_right() {
     let mut buffer = [0u8; 6];
     let i = (-12768i16).numtoa(10, &mut buffer);
@@ -389,6 +664,7 @@ fn base10_i16_array_just_right() {
 }
 
 #[test]
+#[cfg(feature = "u16")]
 #[should_panic]
 fn base10_u16_array_too_small() {
     let mut buffer = [0u8; 4];
@@ -396,12 +672,14 @@ fn base10_u16_array_too_small() {
 }
 
 #[test]
+#[cfg(feature = "u16")]
 fn base10_u16_array_just_right() {
     let mut buffer = [0u8; 5];
     let _ = 0u16.numtoa(10, &mut buffer);
 }
 
 #[test]
+#[cfg(feature = "i32")]
 #[should_panic]
 fn base10_i32_array_too_small() {
     let mut buffer = [0u8; 10];
@@ -409,12 +687,14 @@ fn base10_i32_array_too_small() {
 }
 
 #[test]
+#[cfg(feature = "i32")]
 fn base10_i32_array_just_right() {
     let mut buffer = [0u8; 11];
     let _ = 0i32.numtoa(10, &mut buffer);
 }
 
 #[test]
+#[cfg(feature = "u32")]
 #[should_panic]
 fn base10_u32_array_too_small() {
     let mut buffer = [0u8; 9];
@@ -422,12 +702,14 @@ fn base10_u32_array_too_small() {
 }
 
 #[test]
+#[cfg(feature = "u32")]
 fn base10_u32_array_just_right() {
     let mut buffer = [0u8; 10];
     let _ = 0u32.numtoa(10, &mut buffer);
 }
 
 #[test]
+#[cfg(feature = "i64")]
 #[should_panic]
 fn base10_i64_array_too_small() {
     let mut buffer = [0u8; 19];
@@ -435,12 +717,14 @@ fn base10_i64_array_too_small() {
 }
 
 #[test]
+#[cfg(feature = "i64")]
 fn base10_i64_array_just_right() {
     let mut buffer = [0u8; 20];
     let _ = 0i64.numtoa(10, &mut buffer);
 }
 
 #[test]
+#[cfg(feature = "u64")]
 #[should_panic]
 fn base10_u64_array_too_small() {
     let mut buffer = [0u8; 19];
@@ -448,12 +732,14 @@ fn base10_u64_array_too_small() {
 }
 
 #[test]
+#[cfg(feature = "u64")]
 fn base10_u64_array_just_right() {
     let mut buffer = [0u8; 20];
     let _ = 0u64.numtoa(10, &mut buffer);
 }
 
 #[test]
+#[cfg(all(feature = "i8", feature = "i16", feature = "i32", feature = "i64"))]
 fn base8_min_signed_number() {
     let mut buffer = [0u8; 30];
     let i = (-128i8).numtoa(8, &mut buffer);
@@ -470,6 +756,7 @@ fn base8_min_signed_number() {
 }
 
 #[test]
+#[cfg(all(feature = "i8", feature = "i16", feature = "i32", feature = "i64"))]
 fn base16_min_signed_number() {
     let mut buffer = [0u8; 20];
     let i = (-128i8).numtoa(16, &mut buffer);
@@ -484,3 +771,70 @@ fn base16_min_signed_number() {
     let i = (-9223372036854775808i64).numtoa(16, &mut buffer);
     assert_eq!(&buffer[i..], b"-8000000000000000");
 }
+
+#[test]
+#[cfg(feature = "i8")]
+fn base10_i8_two_digits() {
+    let mut buffer = [0u8; 4];
+    let i = 64i8.numtoa(10, &mut buffer);
+    assert_eq!(&buffer[i..], b"64");
+
+    let i = (-99i8).numtoa(10, &mut buffer);
+    assert_eq!(&buffer[i..], b"-99");
+}
+
+#[test]
+fn lookup_tables() {
+    assert_eq!(&HEX_LOOKUP_UPPER[0x3C * 2..0x3C * 2 + 2], b"3C");
+    assert_eq!(&HEX_LOOKUP_LOWER[0xFF * 2..0xFF * 2 + 2], b"ff");
+    assert_eq!(&DEC_LOOKUP[47 * 2..47 * 2 + 2], b"47");
+    assert_eq!(LOOKUP_LOWER[35], b'z');
+}
+
+#[test]
+#[cfg(all(feature = "i128", feature = "u128"))]
+fn base10_128_bit_bounds() {
+    let mut buffer = [0u8; 40];
+    let i = u128::MAX.numtoa(10, &mut buffer);
+    assert_eq!(&buffer[i..], b"340282366920938463463374607431768211455");
+
+    let i = i128::MIN.numtoa(10, &mut buffer);
+    assert_eq!(&buffer[i..], b"-170141183460469231731687303715884105728");
+
+    let i = i128::MIN.numtoa(16, &mut buffer);
+    assert_eq!(&buffer[i..], b"-80000000000000000000000000000000");
+}
+
+#[test]
+#[cfg(all(feature = "i8", feature = "i16", feature = "i32", feature = "i64", feature = "i128"))]
+fn base10_min_signed_number() {
+    let mut buffer = [0u8; 40];
+    let i = write_i8_base10(i8::MIN, &mut buffer);
+    assert_eq!(&buffer[i..], b"-128");
+
+    let i = write_i16_base10(i16::MIN, &mut buffer);
+    assert_eq!(&buffer[i..], b"-32768");
+
+    let i = write_i32_base10(i32::MIN, &mut buffer);
+    assert_eq!(&buffer[i..], b"-2147483648");
+
+    let i = write_i64_base10(i64::MIN, &mut buffer);
+    assert_eq!(&buffer[i..], b"-9223372036854775808");
+
+    let i = write_i128_base10(i128::MIN, &mut buffer);
+    assert_eq!(&buffer[i..], b"-170141183460469231731687303715884105728");
+}
+
+#[test]
+#[cfg(all(feature = "u8", feature = "u32", feature = "u64"))]
+fn write_functions_match_numtoa() {
+    let mut buffer = [0u8; 20];
+    let i = write_u32(0xBEEF, 16, &mut buffer);
+    assert_eq!(&buffer[i..], b"BEEF");
+
+    let i = write_u64_base10(18446744073709551615, &mut buffer);
+    assert_eq!(&buffer[i..], b"18446744073709551615");
+
+    let i = write_u8(5, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"101");
+}
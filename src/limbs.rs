@@ -0,0 +1,117 @@
+//! Conversion of arbitrarily large numbers stored as little-endian `u64` limbs, as a building block for custom
+//! big number types.
+//!
+//! The number is divided in place by the largest power of the base which fits within a `u64`, and each
+//! remainder is then written out as a fixed-width chunk of digits, so that only one wide division is needed for
+//! many digits.
+
+use super::LOOKUP_UPPER;
+
+// Returns the largest power of `base` which fits in a `u64`, along with the number of digits that it spans.
+fn chunk_for(base: u64) -> (u64, usize) {
+    let mut chunk = base;
+    let mut digits = 1;
+    while let Some(next) = chunk.checked_mul(base) {
+        chunk = next;
+        digits += 1;
+    }
+    (chunk, digits)
+}
+
+// Divides the limbs in place, returning the remainder.
+fn div_rem_in_place(limbs: &mut [u64], divisor: u64) -> u64 {
+    let mut rem = 0u128;
+    for limb in limbs.iter_mut().rev() {
+        let current = (rem << 64) | u128::from(*limb);
+        *limb = (current / u128::from(divisor)) as u64;
+        rem = current % u128::from(divisor);
+    }
+    rem as u64
+}
+
+/// Writes the magnitude held within `limbs` into the end of `string`, preceded by a `-` if `is_negative` is set
+/// and the magnitude is not zero, returning the indice where the inner string begins. The limbs are used as
+/// scratch space for the division, and will be zero once the conversion has completed.
+///
+/// # Panics
+/// If the base is not within `2..=36`, or the buffer is too small to hold the digits, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::limbs::limbs_numtoa;
+///
+/// let mut buffer = [0u8; 48];
+/// let mut limbs = [0, 0, 1];
+/// let start = limbs_numtoa(&mut limbs, true, 10, &mut buffer);
+/// assert_eq!(&buffer[start..], b"-340282366920938463463374607431768211456");
+/// ```
+pub fn limbs_numtoa(limbs: &mut [u64], is_negative: bool, base: u32, string: &mut [u8]) -> usize {
+    assert!((2..=36).contains(&base), "base must be within 2 and 36");
+    let base = u64::from(base);
+    let (chunk, chunk_digits) = chunk_for(base);
+
+    let mut len = limbs.iter().rposition(|&limb| limb != 0).map_or(0, |pos| pos + 1);
+    let mut index = string.len();
+    if len == 0 {
+        index -= 1;
+        string[index] = b'0';
+        return index;
+    }
+
+    while len != 0 {
+        let mut rem = div_rem_in_place(&mut limbs[..len], chunk);
+        while len != 0 && limbs[len - 1] == 0 {
+            len -= 1;
+        }
+
+        // Every chunk except for the most significant one must be padded to its full width with zeros.
+        let mut written = 0;
+        while rem != 0 || (len != 0 && written != chunk_digits) {
+            index -= 1;
+            string[index] = LOOKUP_UPPER[(rem % base) as usize];
+            rem /= base;
+            written += 1;
+        }
+    }
+
+    if is_negative {
+        index -= 1;
+        string[index] = b'-';
+    }
+
+    index
+}
+
+#[test]
+fn limbs_round_trip() {
+    let mut buffer = [0u8; 64];
+
+    let mut limbs = [0, 0, 0];
+    let i = limbs_numtoa(&mut limbs, false, 10, &mut buffer);
+    assert_eq!(&buffer[i..], b"0");
+
+    let mut limbs = [u64::MAX, u64::MAX];
+    let i = limbs_numtoa(&mut limbs, false, 10, &mut buffer);
+    assert_eq!(&buffer[i..], b"340282366920938463463374607431768211455");
+
+    let mut limbs = [0, 1, 0];
+    let i = limbs_numtoa(&mut limbs, false, 16, &mut buffer);
+    assert_eq!(&buffer[i..], b"10000000000000000");
+
+    let mut limbs = [10_000_000_000_000_000_000, 0];
+    let i = limbs_numtoa(&mut limbs, false, 10, &mut buffer);
+    assert_eq!(&buffer[i..], b"10000000000000000000");
+}
+
+#[test]
+fn limbs_negative() {
+    let mut buffer = [0u8; 9];
+
+    let mut limbs = [0];
+    let i = limbs_numtoa(&mut limbs, true, 10, &mut buffer);
+    assert_eq!(&buffer[i..], b"0");
+
+    let mut limbs = [255];
+    let i = limbs_numtoa(&mut limbs, true, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"-11111111");
+}
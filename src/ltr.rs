@@ -0,0 +1,79 @@
+// A cursor which appends to a byte slice from left to right, for the formats which are assembled one field at a
+// time, such as NMEA sentences. Each field is still converted from right to left, into the free end of the slice
+// or into a scratch space, and then moved into place.
+
+use super::rtl::{Rtl, SCRATCH_LEN};
+use super::NumToA;
+
+pub(crate) struct Ltr<'a> {
+    string: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> Ltr<'a> {
+    pub(crate) fn new(string: &'a mut [u8]) -> Self {
+        Ltr { string, len: 0 }
+    }
+
+    // The bytes which have been written so far.
+    pub(crate) fn written(&self) -> &[u8] { &self.string[..self.len] }
+
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        self.string.get_mut(self.len..end).expect("numtoa: buffer is too small").copy_from_slice(bytes);
+        self.len = end;
+    }
+
+    // Appends whatever `write` writes from right to left. It is written at the end of the free space and then moved
+    // to the front of it, so a field may be as long as the space which is left.
+    pub(crate) fn rtl(&mut self, write: impl FnOnce(&mut Rtl)) {
+        let mut rtl = Rtl::new(&mut self.string[self.len..]);
+        write(&mut rtl);
+        let start = self.len + rtl.index();
+        let written = self.string.len() - start;
+        self.string.copy_within(start.., self.len);
+        self.len += written;
+    }
+
+    // Appends the number through a scratch space, since a conversion may require more room than its digits take.
+    pub(crate) fn number<T: NumToA<B>, B>(&mut self, number: T, base: B) {
+        let mut scratch = [0u8; SCRATCH_LEN];
+        let start = number.numtoa(base, &mut scratch);
+        self.bytes(&scratch[start..]);
+    }
+
+    pub(crate) fn into_written(self) -> &'a [u8] {
+        &self.string[..self.len]
+    }
+}
+
+#[test]
+fn ltr_composition() {
+    let mut buffer = [0u8; 8];
+    let mut ltr = Ltr::new(&mut buffer);
+    ltr.bytes(b"x=");
+    ltr.rtl(|rtl| rtl.digits(42));
+    assert_eq!(ltr.written(), b"x=42");
+    assert_eq!(ltr.into_written(), b"x=42");
+}
+
+#[test]
+fn ltr_long_fields() {
+    let mut buffer = [0u8; 256];
+    let mut ltr = Ltr::new(&mut buffer);
+    ltr.bytes(b"x=");
+    ltr.rtl(|rtl| rtl.fixed(5, 200));
+    let written = ltr.into_written();
+    assert_eq!(written.len(), 204);
+    assert_eq!(&written[..4], b"x=0.");
+    assert_eq!(written[203], b'5');
+}
+
+#[test]
+#[should_panic(expected = "numtoa: buffer is too small")]
+fn ltr_field_overflow() {
+    let mut buffer = [0u8; 4];
+    let mut ltr = Ltr::new(&mut buffer);
+    ltr.bytes(b"x=");
+    ltr.rtl(|rtl| rtl.digits(123));
+}
@@ -0,0 +1,84 @@
+//! Lines of the Prometheus text exposition format, for devices which serve `/metrics` from a fixed buffer.
+//!
+//! ```
+//! use numtoa::metrics::format_metric;
+//!
+//! let mut buffer = [0u8; 96];
+//! let labels: [(&[u8], &[u8]); 2] = [(b"method", b"get"), (b"code", b"200")];
+//! let start = format_metric(b"http_requests_total", &labels, 1027, Some(1395066363000), &mut buffer);
+//! assert_eq!(&buffer[start..], b"http_requests_total{method=\"get\",code=\"200\"} 1027 1395066363000\n".as_ref());
+//! ```
+
+use super::rtl::Rtl;
+
+// Writes a signed integer, as the values and timestamps of a metric are.
+fn write_integer(rtl: &mut Rtl, value: i64) {
+    rtl.digits(u128::from(value.unsigned_abs()));
+    if value < 0 {
+        rtl.byte(b'-');
+    }
+}
+
+// Writes a label value between quotes, escaping backslashes, quotes, and line feeds as the format requires.
+fn write_label_value(rtl: &mut Rtl, value: &[u8]) {
+    rtl.byte(b'"');
+    for &byte in value.iter().rev() {
+        match byte {
+            b'\\' => rtl.bytes(b"\\\\"),
+            b'"' => rtl.bytes(b"\\\""),
+            b'\n' => rtl.bytes(b"\\n"),
+            byte => rtl.byte(byte),
+        }
+    }
+    rtl.byte(b'"');
+}
+
+/// Writes a sample line of a metric into the end of the byte slice, such as `name{label="x"} 12345 1712345678901`
+/// followed by a line feed. The labels are written in the given order, and are omitted along with their braces
+/// when there are none. The timestamp is in milliseconds since the Unix epoch, and is omitted when it is `None`.
+/// Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_metric(
+    name: &[u8],
+    labels: &[(&[u8], &[u8])],
+    value: i64,
+    timestamp_ms: Option<i64>,
+    string: &mut [u8],
+) -> usize {
+    let mut rtl = Rtl::new(string);
+    rtl.byte(b'\n');
+    if let Some(timestamp) = timestamp_ms {
+        write_integer(&mut rtl, timestamp);
+        rtl.byte(b' ');
+    }
+    write_integer(&mut rtl, value);
+    rtl.byte(b' ');
+
+    if !labels.is_empty() {
+        rtl.byte(b'}');
+        for (index, &(label, value)) in labels.iter().enumerate().rev() {
+            write_label_value(&mut rtl, value);
+            rtl.byte(b'=');
+            rtl.bytes(label);
+            if index != 0 {
+                rtl.byte(b',');
+            }
+        }
+        rtl.byte(b'{');
+    }
+
+    rtl.bytes(name);
+    rtl.index()
+}
+
+#[test]
+fn metric_lines() {
+    let mut buffer = [0u8; 64];
+    let i = format_metric(b"up", &[], 1, None, &mut buffer);
+    assert_eq!(&buffer[i..], b"up 1\n");
+
+    let i = format_metric(b"temp_celsius", &[(b"zone", b"a\"b\\c\nd")], -40, Some(-1), &mut buffer);
+    assert_eq!(&buffer[i..], b"temp_celsius{zone=\"a\\\"b\\\\c\\nd\"} -40 -1\n".as_ref());
+}
@@ -0,0 +1,112 @@
+//! Output of numbers in Morse code, for beacons and amateur radio firmware which announce their telemetry. Numbers
+//! are written either as text of dots and dashes, or as the timing of the key.
+//!
+//! ```
+//! use numtoa::morse::format_morse;
+//!
+//! let mut buffer = [0u8; 32];
+//! let start = format_morse(-73, &mut buffer);
+//! assert_eq!(&buffer[start..], b"-....- --... ...--");
+//! ```
+
+use super::digits::for_each_digit;
+use super::rtl::Rtl;
+
+const MINUS: &[u8] = b"-....-";
+
+// The Morse code of a decimal digit, which is always five elements long.
+fn digit_code(digit: u32) -> [u8; 5] {
+    let mut code = [b'.'; 5];
+    for (index, element) in code.iter_mut().enumerate() {
+        let dot = if digit <= 5 { (index as u32) < digit } else { (index as u32) >= digit - 5 };
+        if !dot {
+            *element = b'-';
+        }
+    }
+    code
+}
+
+/// Writes the decimal digits of the number into the end of the byte slice in Morse code, with each character
+/// separated by a space, and a leading minus written as `-....-`. Returns the indice where the inner string
+/// begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, which takes six bytes for each digit, this will panic.
+pub fn format_morse(value: i128, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    let mut rest = value.unsigned_abs();
+    loop {
+        rtl.bytes(&digit_code((rest % 10) as u32));
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+        rtl.byte(b' ');
+    }
+
+    if value < 0 {
+        rtl.byte(b' ');
+        rtl.bytes(MINUS);
+    }
+    rtl.index()
+}
+
+/// Calls `f` with each period of the key while sending the number in Morse code, as whether the key is down and
+/// the length of the period in dot units. Dots last one unit and dashes three, with a gap of one unit between
+/// the elements of a character and three between characters. No gap follows the last element.
+///
+/// # Example
+/// ```
+/// use numtoa::morse::for_each_morse_period;
+///
+/// let mut periods = [(false, 0); 16];
+/// let mut count = 0;
+/// for_each_morse_period(7, |keyed, units| {
+///     periods[count] = (keyed, units);
+///     count += 1;
+/// });
+/// // --...
+/// assert_eq!(&periods[..4], &[(true, 3), (false, 1), (true, 3), (false, 1)]);
+/// assert_eq!(count, 9);
+/// ```
+pub fn for_each_morse_period(value: i128, mut f: impl FnMut(bool, u32)) {
+    let mut first = true;
+    let mut send = |code: &[u8]| {
+        if !first {
+            f(false, 3);
+        }
+        first = false;
+        for (index, &element) in code.iter().enumerate() {
+            if index != 0 {
+                f(false, 1);
+            }
+            f(true, if element == b'.' { 1 } else { 3 });
+        }
+    };
+
+    if value < 0 {
+        send(MINUS);
+    }
+    for_each_digit(value.unsigned_abs(), 10, |digit| send(&digit_code(digit)));
+}
+
+#[test]
+fn morse_text() {
+    let mut buffer = [0u8; 64];
+    let i = format_morse(1_234_567_890, &mut buffer);
+    assert_eq!(&buffer[i..], b".---- ..--- ...-- ....- ..... -.... --... ---.. ----. -----");
+
+    let i = format_morse(0, &mut buffer);
+    assert_eq!(&buffer[i..], b"-----");
+}
+
+#[test]
+fn morse_timing() {
+    let (mut units, mut periods) = (0, 0);
+    for_each_morse_period(-5, |_, length| {
+        units += length;
+        periods += 1;
+    });
+    // -....- is 3+1+1+1+1+1+1+1+1+1+3 units, then a gap of 3, then ..... is 9 units.
+    assert_eq!((units, periods), (15 + 3 + 9, 11 + 1 + 9));
+}
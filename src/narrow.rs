@@ -0,0 +1,75 @@
+//! `NumToA` implementations for the non-power-of-two width integers provided by the `ux` and `arbitrary-int`
+//! crates, which register map DSLs commonly use to describe bit fields.
+//!
+//! Each narrow type is converted through the primitive that it is stored in, so the base is given as that
+//! primitive, and the buffer must be large enough for the primitive as well.
+
+use super::NumToA;
+
+#[cfg(feature = "ux")]
+mod ux_impls {
+    use super::NumToA;
+    use ux::*;
+
+    macro_rules! impl_narrow_numtoa_for {
+        ($prim:ty, [$($t:ident),*]) => {$(
+            impl NumToA<$prim> for $t {
+                fn numtoa(self, base: $prim, string: &mut [u8]) -> usize {
+                    <$prim>::from(self).numtoa(base, string)
+                }
+            }
+        )*}
+    }
+
+    impl_narrow_numtoa_for!(u8, [u1, u2, u3, u4, u5, u6, u7]);
+    impl_narrow_numtoa_for!(u16, [u9, u10, u11, u12, u13, u14, u15]);
+    impl_narrow_numtoa_for!(u32, [u17, u18, u19, u20, u21, u22, u23, u24, u25, u26, u27, u28, u29, u30, u31]);
+    impl_narrow_numtoa_for!(u64, [u33, u34, u35, u36, u37, u38, u39, u40, u41, u42, u43, u44, u45, u46, u47, u48,
+                                  u49, u50, u51, u52, u53, u54, u55, u56, u57, u58, u59, u60, u61, u62, u63]);
+    impl_narrow_numtoa_for!(i8, [i2, i3, i4, i5, i6, i7]);
+    impl_narrow_numtoa_for!(i16, [i9, i10, i11, i12, i13, i14, i15]);
+    impl_narrow_numtoa_for!(i32, [i17, i18, i19, i20, i21, i22, i23, i24, i25, i26, i27, i28, i29, i30, i31]);
+    impl_narrow_numtoa_for!(i64, [i33, i34, i35, i36, i37, i38, i39, i40, i41, i42, i43, i44, i45, i46, i47, i48,
+                                  i49, i50, i51, i52, i53, i54, i55, i56, i57, i58, i59, i60, i61, i62, i63]);
+
+    #[test]
+    fn ux_narrow_types() {
+        let mut buffer = [0u8; 20];
+        let i = u7::new(127).numtoa(10, &mut buffer);
+        assert_eq!(&buffer[i..], b"127");
+
+        let i = i33::MIN.numtoa(16, &mut buffer);
+        assert_eq!(&buffer[i..], b"-100000000");
+    }
+}
+
+#[cfg(feature = "arbitrary-int")]
+mod arbitrary_int_impls {
+    use super::NumToA;
+    use arbitrary_int::{Int, UInt};
+
+    macro_rules! impl_arbitrary_int_numtoa_for {
+        ($wrapper:ident, [$($prim:ty),*]) => {$(
+            impl<const BITS: usize> NumToA<$prim> for $wrapper<$prim, BITS> {
+                fn numtoa(self, base: $prim, string: &mut [u8]) -> usize {
+                    self.value().numtoa(base, string)
+                }
+            }
+        )*}
+    }
+
+    impl_arbitrary_int_numtoa_for!(UInt, [u8, u16, u32, u64]);
+    impl_arbitrary_int_numtoa_for!(Int, [i8, i16, i32, i64]);
+
+    #[test]
+    fn arbitrary_int_narrow_types() {
+        use arbitrary_int::{i12, u48};
+
+        let mut buffer = [0u8; 20];
+        let i = u48::new(0xFFFF_FFFF_FFFF).numtoa(16, &mut buffer);
+        assert_eq!(&buffer[i..], b"FFFFFFFFFFFF");
+
+        let i = i12::new(-2048).numtoa(10, &mut buffer);
+        assert_eq!(&buffer[i..], b"-2048");
+    }
+}
@@ -0,0 +1,153 @@
+//! Assembly of NMEA 0183 sentences, as written by GPS receivers and the instruments around them. A sentence is
+//! built from left to right, one comma-separated field at a time, and is closed with its XOR checksum.
+//!
+//! ```
+//! use numtoa::nmea::Sentence;
+//!
+//! let mut buffer = [0u8; 82];
+//! let mut sentence = Sentence::new(b"GPGGA", &mut buffer);
+//! sentence
+//!     .padded(123519, 0, 6, 0)
+//!     .padded(4807038, 3, 4, 3)
+//!     .field(b"N")
+//!     .padded(1131000, 3, 5, 3)
+//!     .field(b"E")
+//!     .field(b"1")
+//!     .padded(8, 0, 2, 0)
+//!     .fixed(9, 1, 1)
+//!     .fixed(5454, 1, 1)
+//!     .field(b"M")
+//!     .fixed(469, 1, 1)
+//!     .field(b"M")
+//!     .empty()
+//!     .empty();
+//! assert_eq!(sentence.finish(), b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n");
+//! ```
+
+use super::fixed::{rescale, write_scaled, Rounding};
+use super::ltr::Ltr;
+use super::NumToA;
+
+/// An NMEA sentence which is being written into a byte slice from left to right.
+pub struct Sentence<'a> {
+    ltr: Ltr<'a>,
+}
+
+impl<'a> Sentence<'a> {
+    /// Begins a sentence with `$` and its address, which is the talker and the sentence type, such as `GPGGA`.
+    ///
+    /// # Panics
+    /// If the supplied buffer is too small to hold the address, this will panic.
+    pub fn new(address: &[u8], string: &'a mut [u8]) -> Self {
+        let mut ltr = Ltr::new(string);
+        ltr.bytes(b"$");
+        ltr.bytes(address);
+        Sentence { ltr }
+    }
+
+    /// Appends a field of literal bytes, such as the `N` of a latitude.
+    ///
+    /// # Panics
+    /// If the supplied buffer is too small to hold the field, this will panic, as do the other fields.
+    pub fn field(&mut self, bytes: &[u8]) -> &mut Self {
+        self.ltr.bytes(b",");
+        self.ltr.bytes(bytes);
+        self
+    }
+
+    /// Appends an empty field, for a value which is not available.
+    pub fn empty(&mut self) -> &mut Self {
+        self.field(b"")
+    }
+
+    /// Appends a field holding the number in the given base.
+    ///
+    /// # Panics
+    /// If the number requires more than 129 bytes, which no primitive integer does, this will panic.
+    pub fn number<T: NumToA<B>, B>(&mut self, number: T, base: B) -> &mut Self {
+        self.ltr.bytes(b",");
+        self.ltr.number(number, base);
+        self
+    }
+
+    /// Appends a field holding a value with `scale` implied decimal places, rounded half up to `decimals`
+    /// fractional digits, such as `545.4`.
+    ///
+    /// # Panics
+    /// If `decimals` exceeds `scale` by so much that the value no longer fits in a `u128`, this will panic.
+    pub fn fixed(&mut self, value: i64, scale: u32, decimals: u32) -> &mut Self {
+        self.ltr.bytes(b",");
+        self.ltr.rtl(|rtl| write_scaled(rtl, value, scale, decimals, Rounding::HalfUp));
+        self
+    }
+
+    /// Appends a field holding a value with `scale` implied decimal places, rounded half up to `decimals`
+    /// fractional digits, with the whole part padded with zeros to `width` digits. This is the convention of
+    /// times such as `123519.00` and of positions such as `4807.038`.
+    ///
+    /// # Panics
+    /// If `decimals` exceeds `scale` by so much that the value no longer fits in a `u128`, this will panic.
+    pub fn padded(&mut self, value: u64, scale: u32, width: usize, decimals: u32) -> &mut Self {
+        let magnitude = rescale(u128::from(value), scale, decimals, Rounding::HalfUp);
+        self.ltr.bytes(b",");
+        self.ltr.rtl(|rtl| rtl.fixed_padded(magnitude, decimals, width));
+        self
+    }
+
+    /// Closes the sentence with `*`, the XOR of every byte between the `$` and the `*` in hexadecimal, and CRLF,
+    /// returning the written sentence.
+    ///
+    /// # Panics
+    /// If the supplied buffer is too small to hold the checksum, this will panic.
+    pub fn finish(mut self) -> &'a [u8] {
+        let checksum = self.ltr.written()[1..].iter().fold(0u8, |sum, &byte| sum ^ byte);
+        self.ltr.rtl(|rtl| {
+            rtl.bytes(b"\r\n");
+            rtl.hex_byte(checksum);
+            rtl.byte(b'*');
+        });
+        self.ltr.into_written()
+    }
+}
+
+#[test]
+#[cfg(feature = "u32")]
+fn nmea_sentences() {
+    let mut buffer = [0u8; 82];
+    let mut sentence = Sentence::new(b"GPRMC", &mut buffer);
+    sentence
+        .padded(225446, 0, 6, 0)
+        .field(b"A")
+        .padded(491645, 2, 4, 2)
+        .field(b"N")
+        .padded(1231112, 2, 5, 2)
+        .field(b"W")
+        .fixed(5, 1, 3)
+        .number(54u32, 10)
+        .padded(191194, 0, 6, 0)
+        .fixed(200, 2, 1)
+        .field(b"E");
+    assert_eq!(sentence.finish(), b"$GPRMC,225446,A,4916.45,N,12311.12,W,0.500,54,191194,2.0,E*42\r\n");
+
+    let mut buffer = [0u8; 16];
+    let sentence = Sentence::new(b"PXXX", &mut buffer);
+    assert_eq!(sentence.finish(), b"$PXXX*08\r\n");
+}
+
+#[test]
+#[should_panic]
+fn nmea_overflow() {
+    let mut buffer = [0u8; 8];
+    Sentence::new(b"GPGGA", &mut buffer).finish();
+}
+
+#[test]
+fn nmea_wide_fields() {
+    let mut buffer = [0u8; 256];
+    let mut sentence = Sentence::new(b"GPZDA", &mut buffer);
+    sentence.padded(7, 0, 150, 0).fixed(1, 80, 80);
+    let written = sentence.finish();
+    assert_eq!(written.len(), 6 + 151 + 83 + 5);
+    assert_eq!(&written[154..158], b"007,");
+    assert_eq!(&written[written.len() - 11..written.len() - 5], b"000001");
+}
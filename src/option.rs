@@ -0,0 +1,45 @@
+//! Formatting of values which may be absent, such as sensor readings which have not arrived yet.
+
+use super::NumToA;
+
+/// Writes the value into the end of the byte slice when it is present, or the placeholder when it is `None`,
+/// returning the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is smaller than the value or the placeholder, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::option::numtoa_option;
+///
+/// let mut buffer = [0u8; 20];
+/// let start = numtoa_option(Some(42u32), 10, b"--", &mut buffer);
+/// assert_eq!(&buffer[start..], b"42");
+///
+/// let start = numtoa_option(None::<u32>, 10, b"--", &mut buffer);
+/// assert_eq!(&buffer[start..], b"--");
+/// ```
+pub fn numtoa_option<T: NumToA<B>, B>(value: Option<T>, base: B, placeholder: &[u8], string: &mut [u8]) -> usize {
+    match value {
+        Some(value) => value.numtoa(base, string),
+        None => {
+            let start = string.len() - placeholder.len();
+            string[start..].copy_from_slice(placeholder);
+            start
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "i16")]
+fn option_placeholders() {
+    let mut buffer = [0u8; 8];
+    let i = numtoa_option(Some(-40i16), 10, b"null", &mut buffer);
+    assert_eq!(&buffer[i..], b"-40");
+
+    let i = numtoa_option(None::<i16>, 10, b"null", &mut buffer);
+    assert_eq!(&buffer[i..], b"null");
+
+    let i = numtoa_option(None::<i16>, 10, b"", &mut buffer);
+    assert_eq!(&buffer[i..], b"");
+}
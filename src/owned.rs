@@ -0,0 +1,52 @@
+//! Conveniences for converting numbers into owned strings and vectors, which still use the conversions of this
+//! crate internally. Requires the `alloc` feature.
+//!
+//! ```
+//! use numtoa::owned::{extend_numtoa, to_string_radix};
+//!
+//! assert_eq!(to_string_radix(255u8, 16), "FF");
+//!
+//! let mut line = b"count=".to_vec();
+//! extend_numtoa(&mut line, -42i32, 10);
+//! assert_eq!(line, b"count=-42");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::extend::ExtendNumToA;
+use super::NumToA;
+
+/// Appends the number in the given base to the end of the vector.
+///
+/// # Panics
+/// If the number requires more than 129 bytes, which no primitive integer does, this will panic.
+pub fn extend_numtoa<T: NumToA<B>, B>(vec: &mut Vec<u8>, number: T, base: B) {
+    vec.extend_numtoa(number, base);
+}
+
+/// Converts the number into a newly-allocated vector of bytes in the given base.
+///
+/// # Panics
+/// If the number requires more than 129 bytes, which no primitive integer does, this will panic.
+pub fn to_vec_radix<T: NumToA<B>, B>(number: T, base: B) -> Vec<u8> {
+    let mut vec = Vec::new();
+    extend_numtoa(&mut vec, number, base);
+    vec
+}
+
+/// Converts the number into a newly-allocated string in the given base.
+///
+/// # Panics
+/// If the number requires more than 129 bytes, which no primitive integer does, this will panic.
+pub fn to_string_radix<T: NumToA<B>, B>(number: T, base: B) -> String {
+    String::from_utf8(to_vec_radix(number, base)).expect("numtoa: digits are always ASCII")
+}
+
+#[test]
+#[cfg(all(feature = "u64", feature = "i128"))]
+fn owned_conversions() {
+    assert_eq!(to_string_radix(u64::MAX, 10), "18446744073709551615");
+    assert_eq!(to_vec_radix(i128::MIN, 2).len(), 129);
+    assert_eq!(to_vec_radix(0u64, 36), b"0".to_vec());
+}
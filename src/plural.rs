@@ -0,0 +1,108 @@
+//! The CLDR plural category of a number, so that interface code can pick between the forms of a word, such as
+//! `1 file` and `2 files`, alongside the formatted number.
+//!
+//! ```
+//! use numtoa::plural::{PluralCategory, PluralRules};
+//!
+//! assert_eq!(PluralRules::English.category(1), PluralCategory::One);
+//! assert_eq!(PluralRules::English.category(2), PluralCategory::Other);
+//!
+//! // 22 файла, 25 файлов
+//! assert_eq!(PluralRules::EastSlavic.category(22), PluralCategory::Few);
+//! assert_eq!(PluralRules::EastSlavic.category(25), PluralCategory::Many);
+//! ```
+
+/// The categories which the forms of a word are chosen by, as the Unicode CLDR names them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    /// The singular form, such as `1 file`.
+    One,
+    /// The paucal form of Slavic languages, such as `2 файла`.
+    Few,
+    /// The form of Slavic languages for larger counts, such as `5 файлов`.
+    Many,
+    /// The general form, such as `2 files`.
+    #[default]
+    Other,
+}
+
+impl PluralCategory {
+    /// The CLDR name of the category, such as `one`, for keying a table of translations.
+    pub fn name(self) -> &'static str {
+        match self {
+            PluralCategory::One   => "one",
+            PluralCategory::Few   => "few",
+            PluralCategory::Many  => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// The rules of a language, or of a family of languages which share them, for choosing a plural category.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PluralRules {
+    /// English, which has `one` for exactly 1 and `other` for everything else.
+    #[default]
+    English,
+    /// Russian, Ukrainian, and Belarusian, where counts ending in 1 are `one`, those ending in 2 through 4 are
+    /// `few`, and the rest are `many`, except for the teens, which are always `many`.
+    EastSlavic,
+    /// Polish, which is as East Slavic, except that only 1 itself is `one`.
+    Polish,
+    /// Czech and Slovak, where 1 is `one`, 2 through 4 are `few`, other integers are `other`, and numbers with
+    /// fractional digits are `many`.
+    CzechSlovak,
+}
+
+impl PluralRules {
+    /// The category of an integer.
+    pub fn category(self, value: i64) -> PluralCategory {
+        self.category_fixed(value, 0)
+    }
+
+    /// The category of `value / 10^decimals` as it is written with exactly `decimals` fractional digits, which
+    /// matters because the rules of CLDR distinguish `1 file` from `1.0 files`. With more decimals than an `i64`
+    /// has digits, the integer part is zero.
+    pub fn category_fixed(self, value: i64, decimals: u32) -> PluralCategory {
+        let integer = 10u64.checked_pow(decimals).map_or(0, |divisor| value.unsigned_abs() / divisor);
+        let (last, last_two) = (integer % 10, integer % 100);
+        let few = (2..=4).contains(&last) && !(12..=14).contains(&last_two);
+
+        match self {
+            PluralRules::CzechSlovak if decimals != 0 => PluralCategory::Many,
+            _ if decimals != 0 => PluralCategory::Other,
+            _ if integer == 1 => PluralCategory::One,
+            PluralRules::English => PluralCategory::Other,
+            PluralRules::EastSlavic if last == 1 && last_two != 11 => PluralCategory::One,
+            PluralRules::EastSlavic | PluralRules::Polish if few => PluralCategory::Few,
+            PluralRules::EastSlavic | PluralRules::Polish => PluralCategory::Many,
+            PluralRules::CzechSlovak if (2..=4).contains(&integer) => PluralCategory::Few,
+            PluralRules::CzechSlovak => PluralCategory::Other,
+        }
+    }
+}
+
+#[test]
+fn plural_english() {
+    assert_eq!(PluralRules::English.category(0), PluralCategory::Other);
+    assert_eq!(PluralRules::English.category(-1), PluralCategory::One);
+    assert_eq!(PluralRules::English.category(21), PluralCategory::Other);
+    assert_eq!(PluralRules::English.category_fixed(10, 1), PluralCategory::Other);
+}
+
+#[test]
+fn plural_slavic() {
+    use self::PluralCategory::{Few, Many, One, Other};
+    let categories = |rules: PluralRules| [1, 2, 5, 11, 12, 21, 22, 25, 101, 112].map(|n| rules.category(n));
+
+    assert_eq!(categories(PluralRules::EastSlavic), [One, Few, Many, Many, Many, One, Few, Many, One, Many]);
+    assert_eq!(categories(PluralRules::Polish), [One, Few, Many, Many, Many, Many, Few, Many, Many, Many]);
+    let czech_slovak = [One, Few, Other, Other, Other, Other, Other, Other, Other, Other];
+    assert_eq!(categories(PluralRules::CzechSlovak), czech_slovak);
+
+    assert_eq!(PluralRules::EastSlavic.category_fixed(15, 1), Other);
+    assert_eq!(PluralRules::CzechSlovak.category_fixed(15, 1), Many);
+
+    assert_eq!(PluralRules::EastSlavic.category_fixed(i64::MIN, 20), Other);
+    assert_eq!(PluralRules::CzechSlovak.category_fixed(1, u32::MAX), Many);
+}
@@ -0,0 +1,163 @@
+//! Composite positional formatting, where a value is split into several fields which are each written in their
+//! own base, such as hours, minutes, and seconds.
+//!
+//! ```
+//! use numtoa::positional::format_sexagesimal;
+//!
+//! // A duration in seconds, as HH:MM:SS.
+//! let mut buffer = [0u8; 24];
+//! let start = format_sexagesimal(3725, &[2, 2, 2], &[b":", b":"], &mut buffer);
+//! assert_eq!(&buffer[start..], b"01:02:05");
+//!
+//! // An angle in arcseconds, as degrees, minutes, and seconds.
+//! let start = format_sexagesimal(175_889, &[1, 2, 2], &["°".as_bytes(), b"'"], &mut buffer);
+//! assert_eq!(&buffer[start..], "48°51'29".as_bytes());
+//! ```
+//!
+//! Fields with differing bases are supported as well, such as the odometer-style timecode of `HH:MM:SS:FF`.
+//!
+//! ```
+//! use numtoa::positional::format_mixed_radix;
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_mixed_radix(90_061_001, &[24, 60, 60, 1000], &[b":", b":", b"."], &mut buffer);
+//! assert_eq!(&buffer[start..], b"01:01:01.001");
+//! ```
+
+use super::rtl::Rtl;
+
+// Writes the least significant field of the remaining value, zero padded to the width of the largest digit in
+// the field's base, preceded by the separator. The remaining value is then shifted down by the field.
+fn write_field(rtl: &mut Rtl, remaining: &mut u64, radix: u64, separator: &[u8]) {
+    let mut width = 1;
+    let mut largest = radix - 1;
+    while largest >= 10 {
+        largest /= 10;
+        width += 1;
+    }
+
+    rtl.digits_padded(u128::from(*remaining % radix), width, b'0');
+    rtl.bytes(separator);
+    *remaining /= radix;
+}
+
+/// Writes a value in base 60 into the end of the byte slice, with one field more than there are separators.
+/// Each separator is written after the field at the same position, and each field is padded with zeros to at
+/// least the width at the same position in `widths`, from the most significant field to the least. The minor
+/// fields hold from 0 to 59, whereas the major field holds everything that remains. Negative values are written
+/// with a leading `-`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If there is not exactly one width for each field, or the supplied buffer is too small to hold the output,
+/// this will panic.
+pub fn format_sexagesimal(value: i64, widths: &[usize], separators: &[&[u8]], string: &mut [u8]) -> usize {
+    assert!(widths.len() == separators.len() + 1, "numtoa: expected one width for each field");
+    let (&major_width, minor_widths) = widths.split_first().expect("numtoa: expected at least one width");
+
+    let mut rtl = Rtl::new(string);
+    let mut remaining = value.unsigned_abs();
+    for (&width, separator) in minor_widths.iter().zip(separators).rev() {
+        rtl.digits_padded(u128::from(remaining % 60), width, b'0');
+        rtl.bytes(separator);
+        remaining /= 60;
+    }
+
+    rtl.digits_padded(u128::from(remaining), major_width, b'0');
+    if value < 0 {
+        rtl.byte(b'-');
+    }
+    rtl.index()
+}
+
+/// Writes a value into the end of the byte slice as a series of fields, given the base of each field from the
+/// most significant to the least. Each field is zero padded to the width of its largest digit, and separated
+/// from the next field by the separator at the same position. The value wraps around at the base of the most
+/// significant field, as an odometer would. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If there is not exactly one less separator than there are bases, if any base is less than 2, or if the
+/// supplied buffer is too small to hold the output, this will panic.
+pub fn format_mixed_radix(value: u64, radixes: &[u64], separators: &[&[u8]], string: &mut [u8]) -> usize {
+    assert!(radixes.len() == separators.len() + 1, "numtoa: expected one less separator than bases");
+    assert!(radixes.iter().all(|&radix| radix >= 2), "numtoa: bases must be at least 2");
+
+    let (&major, minor) = radixes.split_first().expect("numtoa: expected at least one base");
+    let mut rtl = Rtl::new(string);
+    let mut remaining = value;
+    for (&radix, separator) in minor.iter().zip(separators).rev() {
+        write_field(&mut rtl, &mut remaining, radix, separator);
+    }
+    write_field(&mut rtl, &mut remaining, major, b"");
+    rtl.index()
+}
+
+/// Writes a counter into the end of the byte slice as an odometer shows it, with exactly `digits` decimal digits
+/// including leading zeros. The counter rolls over modulo `10^digits`, in both directions, so that a counter of
+/// 100,001 shows as `00001` on five digits and one of -1 as `99999`. Returns the indice where the inner string
+/// begins.
+///
+/// # Panics
+/// If `digits` is not between 1 and 38, or the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::positional::format_odometer;
+///
+/// let mut buffer = [0u8; 8];
+/// let start = format_odometer(1_234_567, 6, &mut buffer);
+/// assert_eq!(&buffer[start..], b"234567");
+/// ```
+pub fn format_odometer(value: i128, digits: u32, string: &mut [u8]) -> usize {
+    assert!((1..=38).contains(&digits), "numtoa: odometers have 1 to 38 digits");
+    let shown = value.rem_euclid(10i128.pow(digits));
+    let mut rtl = Rtl::new(string);
+    rtl.digits_padded(shown as u128, digits as usize, b'0');
+    rtl.index()
+}
+
+#[test]
+fn sexagesimal_fields() {
+    let mut buffer = [0u8; 32];
+    let i = format_sexagesimal(0, &[1, 2], &[b":"], &mut buffer);
+    assert_eq!(&buffer[i..], b"0:00");
+
+    let i = format_sexagesimal(-(100 * 3600 + 59), &[2, 2, 2], &[b":", b":"], &mut buffer);
+    assert_eq!(&buffer[i..], b"-100:00:59");
+
+    let i = format_sexagesimal(45, &[3], &[], &mut buffer);
+    assert_eq!(&buffer[i..], b"045");
+
+    // Unpadded minor fields, as with an angle of 5° 3' 7".
+    let i = format_sexagesimal(5 * 3600 + 3 * 60 + 7, &[3, 1, 1], &["°".as_bytes(), b"'"], &mut buffer);
+    assert_eq!(&buffer[i..], "005°3'7".as_bytes());
+}
+
+#[test]
+fn mixed_radix_fields() {
+    let mut buffer = [0u8; 32];
+    let i = format_mixed_radix(0, &[24, 60, 60, 1000], &[b":", b":", b"."], &mut buffer);
+    assert_eq!(&buffer[i..], b"00:00:00.000");
+
+    // One day and one millisecond wraps around to the first millisecond of the day.
+    let i = format_mixed_radix(86_400_001, &[24, 60, 60, 1000], &[b":", b":", b"."], &mut buffer);
+    assert_eq!(&buffer[i..], b"00:00:00.001");
+
+    let i = format_mixed_radix(0b1011, &[2, 2, 2, 2], &[b"", b"", b""], &mut buffer);
+    assert_eq!(&buffer[i..], b"1011");
+}
+
+#[test]
+fn odometer_rollover() {
+    let mut buffer = [0u8; 40];
+    let i = format_odometer(0, 5, &mut buffer);
+    assert_eq!(&buffer[i..], b"00000");
+
+    let i = format_odometer(100_001, 5, &mut buffer);
+    assert_eq!(&buffer[i..], b"00001");
+
+    let i = format_odometer(-1, 5, &mut buffer);
+    assert_eq!(&buffer[i..], b"99999");
+
+    let i = format_odometer(i128::MIN, 38, &mut buffer);
+    assert_eq!(&buffer[i..], b"29858816539530768268312696284115894272");
+}
@@ -0,0 +1,217 @@
+//! Parsing of integers from their string representations in a given base, along with wrapper types which tag
+//! an integer with the base that it should be parsed from.
+//!
+//! The wrappers implement both `FromStr` and `TryFrom<&str>`, so argument structs in `std` binaries and
+//! `no_std` configuration layers are able to share one parsing code path.
+//!
+//! ```
+//! use numtoa::radix::{Bin, Hex};
+//!
+//! let Hex(address) = "0xDEADBEEF".parse::<Hex<u32>>().unwrap();
+//! assert_eq!(address, 0xDEADBEEF);
+//!
+//! let Bin(mask) = "1010".parse::<Bin<u16>>().unwrap();
+//! assert_eq!(mask, 0b1010);
+//! ```
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+/// The reason that a string could not be parsed as an integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseRadixError {
+    /// The input contained no digits.
+    Empty,
+    /// The input contained a byte which is not a valid digit in the requested base.
+    InvalidDigit,
+    /// The value does not fit within the target integer type.
+    Overflow,
+}
+
+impl fmt::Display for ParseRadixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ParseRadixError::Empty        => "cannot parse integer from empty string",
+            ParseRadixError::InvalidDigit => "invalid digit found in string",
+            ParseRadixError::Overflow     => "number too large to fit in target type",
+        })
+    }
+}
+
+/// Parses a number from a byte slice, which is the inverse of `NumToA`.
+pub trait FromRadix: Sized {
+    /// Parses the digits within `string` as a number in the given base. Both upper and lower case letters are
+    /// accepted for bases above 10, and signed types accept a leading `-` or `+`.
+    ///
+    /// # Panics
+    /// If the base is not within the range of `2..=36`, this will panic.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::radix::FromRadix;
+    ///
+    /// assert_eq!(i16::from_radix(b"-7fff", 16), Ok(-0x7FFF));
+    /// ```
+    fn from_radix(string: &[u8], base: u32) -> Result<Self, ParseRadixError> {
+        match string.split_first() {
+            Some((&b'-', digits)) => Self::from_radix_digits(digits, true, base),
+            Some((&b'+', digits)) => Self::from_radix_digits(digits, false, base),
+            _                     => Self::from_radix_digits(string, false, base),
+        }
+    }
+
+    /// Parses digits without a sign as a number in the given base, negated when `is_negative` is set. This lets
+    /// callers split the sign off apart from the digits, such as when a `0x` prefix sits between them. Unsigned
+    /// types reject a negative sign as an invalid digit.
+    ///
+    /// # Panics
+    /// If the base is not within the range of `2..=36`, this will panic.
+    fn from_radix_digits(digits: &[u8], is_negative: bool, base: u32) -> Result<Self, ParseRadixError>;
+}
+
+// Maps an ASCII byte to its digit value, returning a value above 35 for bytes which are never digits.
+fn digit_value(byte: u8) -> u32 {
+    match byte {
+        b'0'..=b'9' => u32::from(byte - b'0'),
+        b'a'..=b'z' => u32::from(byte - b'a') + 10,
+        b'A'..=b'Z' => u32::from(byte - b'A') + 10,
+        _           => u32::MAX,
+    }
+}
+
+macro_rules! impl_from_radix_for {
+    ($t:ty, $signed:expr) => {
+        impl FromRadix for $t {
+            fn from_radix_digits(digits: &[u8], is_negative: bool, base: u32) -> Result<$t, ParseRadixError> {
+                assert!((2..=36).contains(&base), "base must be within 2 and 36");
+
+                if is_negative && !$signed {
+                    return Err(ParseRadixError::InvalidDigit);
+                }
+
+                if digits.is_empty() {
+                    return Err(ParseRadixError::Empty);
+                }
+
+                // Negative values are accumulated downwards so that the minimum value of the type can be parsed.
+                let mut value: $t = 0;
+                for &byte in digits {
+                    let digit = digit_value(byte);
+                    if digit >= base {
+                        return Err(ParseRadixError::InvalidDigit);
+                    }
+
+                    value = value.checked_mul(base as $t).ok_or(ParseRadixError::Overflow)?;
+                    value = if is_negative {
+                        value.checked_sub(digit as $t)
+                    } else {
+                        value.checked_add(digit as $t)
+                    }.ok_or(ParseRadixError::Overflow)?;
+                }
+
+                Ok(value)
+            }
+        }
+    }
+}
+
+impl_from_radix_for!(i8, true);
+impl_from_radix_for!(i16, true);
+impl_from_radix_for!(i32, true);
+impl_from_radix_for!(i64, true);
+impl_from_radix_for!(isize, true);
+impl_from_radix_for!(u8, false);
+impl_from_radix_for!(u16, false);
+impl_from_radix_for!(u32, false);
+impl_from_radix_for!(u64, false);
+impl_from_radix_for!(usize, false);
+
+// Parses a string with an optional sign, followed by an optional prefix, followed by the digits.
+fn parse_prefixed<T: FromRadix>(string: &str, base: u32, prefix: &[u8]) -> Result<T, ParseRadixError> {
+    let bytes = string.as_bytes();
+    let (is_negative, rest) = match bytes.split_first() {
+        Some((&b'-', rest)) => (true, rest),
+        Some((&b'+', rest)) => (false, rest),
+        _                   => (false, bytes),
+    };
+
+    let has_prefix = rest.len() >= 2 && rest[0] == b'0' && rest[1].to_ascii_lowercase() == prefix[1];
+    let digits = if has_prefix { &rest[2..] } else { rest };
+    T::from_radix_digits(digits, is_negative, base)
+}
+
+macro_rules! radix_wrapper {
+    ($(#[$attr:meta])* $name:ident, $base:expr, $prefix:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T> {
+            /// Unwraps the inner value.
+            pub fn into_inner(self) -> T { self.0 }
+        }
+
+        impl<T: FromRadix> FromStr for $name<T> {
+            type Err = ParseRadixError;
+
+            fn from_str(string: &str) -> Result<Self, ParseRadixError> {
+                parse_prefixed(string, $base, $prefix).map($name)
+            }
+        }
+
+        impl<'a, T: FromRadix> TryFrom<&'a str> for $name<T> {
+            type Error = ParseRadixError;
+
+            fn try_from(string: &'a str) -> Result<Self, ParseRadixError> {
+                string.parse()
+            }
+        }
+    }
+}
+
+radix_wrapper!(
+    /// An integer parsed from hexadecimal digits, with an optional `0x` prefix.
+    Hex, 16, b"0x"
+);
+
+radix_wrapper!(
+    /// An integer parsed from octal digits, with an optional `0o` prefix.
+    Oct, 8, b"0o"
+);
+
+radix_wrapper!(
+    /// An integer parsed from binary digits, with an optional `0b` prefix.
+    Bin, 2, b"0b"
+);
+
+#[test]
+fn from_radix_bounds() {
+    assert_eq!(i8::from_radix(b"-128", 10), Ok(-128));
+    assert_eq!(i8::from_radix(b"127", 10), Ok(127));
+    assert_eq!(i8::from_radix(b"128", 10), Err(ParseRadixError::Overflow));
+    assert_eq!(u8::from_radix(b"-1", 10), Err(ParseRadixError::InvalidDigit));
+    assert_eq!(u64::from_radix(b"FFFFFFFFFFFFFFFF", 16), Ok(u64::MAX));
+    assert_eq!(u32::from_radix(b"+", 10), Err(ParseRadixError::Empty));
+    assert_eq!(u32::from_radix(b"12z", 10), Err(ParseRadixError::InvalidDigit));
+}
+
+#[test]
+fn radix_wrappers() {
+    assert_eq!("ff".parse::<Hex<u8>>(), Ok(Hex(255)));
+    assert_eq!("0XfF".parse::<Hex<u8>>(), Ok(Hex(255)));
+    assert_eq!("-0x80".parse::<Hex<i8>>(), Ok(Hex(-128)));
+    assert_eq!(Oct::<u16>::try_from("0o777"), Ok(Oct(0o777)));
+    assert_eq!(Bin::<u16>::try_from("0b"), Err(ParseRadixError::Empty));
+    assert_eq!(Bin::<u8>::try_from("0b102"), Err(ParseRadixError::InvalidDigit));
+    assert_eq!("0x-5".parse::<Hex<i8>>(), Err(ParseRadixError::InvalidDigit));
+    assert_eq!("-0x1".parse::<Hex<u8>>(), Err(ParseRadixError::InvalidDigit));
+
+    // Leading zeros are accepted however many there are, with or without the prefix.
+    let mut padded = [b'0'; 300];
+    padded[1] = b'x';
+    padded[299] = b'7';
+    let padded = core::str::from_utf8(&padded).unwrap();
+    assert_eq!(padded.parse::<Hex<u8>>(), Ok(Hex(7)));
+    assert_eq!(padded[2..].parse::<Hex<u8>>(), Ok(Hex(7)));
+}
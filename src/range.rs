@@ -0,0 +1,66 @@
+//! Formatting of intervals as their two endpoints joined by a separator, such as `10–20` or `10..=20`, with an
+//! interval whose endpoints are equal written as the single value.
+//!
+//! ```
+//! # #[cfg(feature = "u16")] {
+//! use numtoa::range::{numtoa_range, EN_DASH};
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = numtoa_range(10u16, 20u16, 10, EN_DASH, &mut buffer);
+//! assert_eq!(&buffer[start..], "10–20".as_bytes());
+//!
+//! let start = numtoa_range(7u16, 7u16, 10, EN_DASH, &mut buffer);
+//! assert_eq!(&buffer[start..], b"7");
+//! # }
+//! ```
+
+use super::rtl::{Rtl, SCRATCH_LEN};
+use super::NumToA;
+
+/// The en dash of typeset ranges, `–`, in UTF-8.
+pub const EN_DASH: &[u8] = "–".as_bytes();
+
+/// The separator of inclusive ranges in Rust, `..=`.
+pub const INCLUSIVE: &[u8] = b"..=";
+
+/// The separator of half-open ranges in Rust, `..`.
+pub const EXCLUSIVE: &[u8] = b"..";
+
+/// Writes both endpoints of the range in the given base into the end of the byte slice, joined by the separator,
+/// or only one of them when they are equal. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn numtoa_range<T: NumToA<B> + PartialEq, B: Copy>(
+    start: T,
+    end: T,
+    base: B,
+    separator: &[u8],
+    string: &mut [u8],
+) -> usize {
+    let mut rtl = Rtl::new(string);
+    let mut scratch = [0u8; SCRATCH_LEN];
+    if start != end {
+        let index = end.numtoa(base, &mut scratch);
+        rtl.bytes(&scratch[index..]);
+        rtl.bytes(separator);
+    }
+
+    let index = start.numtoa(base, &mut scratch);
+    rtl.bytes(&scratch[index..]);
+    rtl.index()
+}
+
+#[test]
+#[cfg(feature = "i32")]
+fn ranges() {
+    let mut buffer = [0u8; 24];
+    let i = numtoa_range(-5i32, 5i32, 10, INCLUSIVE, &mut buffer);
+    assert_eq!(&buffer[i..], b"-5..=5");
+
+    let i = numtoa_range(0x10i32, 0x20i32, 16, EXCLUSIVE, &mut buffer);
+    assert_eq!(&buffer[i..], b"10..20");
+
+    let i = numtoa_range(-1i32, -1i32, 10, INCLUSIVE, &mut buffer);
+    assert_eq!(&buffer[i..], b"-1");
+}
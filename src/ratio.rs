@@ -0,0 +1,124 @@
+//! Formatting of integer ratios as percentages, per-mille, and parts-per-million, without floating point.
+//!
+//! ```
+//! use numtoa::ratio::{format_ratio, RatioUnit};
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_ratio(17, 4096, RatioUnit::PartsPerMillion, 0, &mut buffer);
+//! assert_eq!(&buffer[start..], b"4150 ppm");
+//! ```
+
+use super::fixed::{convert, Rounding};
+use super::rtl::Rtl;
+
+/// The unit that a ratio is expressed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RatioUnit {
+    /// Parts per hundred, suffixed with `%`.
+    Percent,
+    /// Parts per thousand, suffixed with `‰`.
+    PerMille,
+    /// Parts per million, suffixed with ` ppm`.
+    PartsPerMillion,
+}
+
+impl RatioUnit {
+    /// The number of parts that a whole is divided into.
+    pub fn parts(self) -> u64 {
+        match self {
+            RatioUnit::Percent         => 100,
+            RatioUnit::PerMille        => 1000,
+            RatioUnit::PartsPerMillion => 1_000_000,
+        }
+    }
+
+    /// The suffix which is written after the number.
+    pub fn suffix(self) -> &'static [u8] {
+        match self {
+            RatioUnit::Percent         => b"%",
+            RatioUnit::PerMille        => "‰".as_bytes(),
+            RatioUnit::PartsPerMillion => b" ppm",
+        }
+    }
+}
+
+/// Writes `numerator / denominator` into the end of the byte slice in the given unit with `decimals` fractional
+/// digits, followed by the unit's suffix, returning the indice where the inner string begins. The last digit is
+/// rounded half up.
+///
+/// # Panics
+/// If the denominator is zero, `decimals` is so large that the ratio no longer fits in a `u128` before it is
+/// divided, or the supplied buffer is too small to hold the output, this will panic.
+pub fn format_ratio(numerator: u64, denominator: u64, unit: RatioUnit, decimals: u32, string: &mut [u8]) -> usize {
+    assert!(denominator != 0, "numtoa: ratio denominator is zero");
+    let parts = u128::from(unit.parts());
+    let magnitude = convert(u128::from(numerator), parts, u128::from(denominator), decimals, Rounding::HalfUp);
+
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(unit.suffix());
+    rtl.fixed(magnitude, decimals);
+    rtl.index()
+}
+
+/// Writes the duty cycle of a PWM channel into the end of the byte slice as a percentage with `decimals` fractional
+/// digits, such as `42.5%`, given the compare value of the channel and the top value of its timer. The period of
+/// the timer is `top + 1` counts, as with the auto-reload register of an STM32, and a compare value beyond the
+/// period holds the output on, which is written as `100%`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If `decimals` is so large that the percentage no longer fits in a `u128`, or the supplied buffer is too small to
+/// hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::ratio::format_duty;
+///
+/// let mut buffer = [0u8; 16];
+/// let start = format_duty(425, 999, 1, &mut buffer);
+/// assert_eq!(&buffer[start..], b"42.5%");
+/// ```
+pub fn format_duty(counts: u32, top: u32, decimals: u32, string: &mut [u8]) -> usize {
+    let period = u64::from(top) + 1;
+    format_ratio(u64::from(counts).min(period), period, RatioUnit::Percent, decimals, string)
+}
+
+#[test]
+fn ratio_units() {
+    let mut buffer = [0u8; 48];
+    let i = format_ratio(1, 3, RatioUnit::Percent, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"33.33%");
+
+    let i = format_ratio(2, 3, RatioUnit::Percent, 0, &mut buffer);
+    assert_eq!(&buffer[i..], b"67%");
+
+    let i = format_ratio(5, 2000, RatioUnit::PerMille, 1, &mut buffer);
+    assert_eq!(&buffer[i..], "2.5‰".as_bytes());
+
+    let i = format_ratio(u64::MAX, 1, RatioUnit::PartsPerMillion, 3, &mut buffer);
+    assert_eq!(&buffer[i..], b"18446744073709551615000000.000 ppm");
+
+    let i = format_ratio(u64::MAX, 1, RatioUnit::PartsPerMillion, 13, &mut buffer);
+    assert_eq!(&buffer[i..], b"18446744073709551615000000.0000000000000 ppm");
+}
+
+#[test]
+#[should_panic(expected = "numtoa: fixed-point value overflows")]
+fn ratio_overflow() {
+    format_ratio(u64::MAX, 1, RatioUnit::PartsPerMillion, 19, &mut [0u8; 64]);
+}
+
+#[test]
+fn duty_cycles() {
+    let mut buffer = [0u8; 16];
+    let i = format_duty(0, 255, 1, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.0%");
+
+    let i = format_duty(128, 255, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"50.00%");
+
+    let i = format_duty(300, 255, 0, &mut buffer);
+    assert_eq!(&buffer[i..], b"100%");
+
+    let i = format_duty(u32::MAX, u32::MAX, 3, &mut buffer);
+    assert_eq!(&buffer[i..], b"100.000%");
+}
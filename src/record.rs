@@ -0,0 +1,73 @@
+//! Encoding of numbers into fixed-width records, where every field sits at a fixed offset of the record, as in
+//! mainframe-style files and industrial fixed-record protocols. The layout is declared once as a table of fields.
+//!
+//! ```
+//! # #[cfg(feature = "u32")] {
+//! use numtoa::record::{encode_record, Field};
+//!
+//! // An account number, a balance, and a status code, with a literal `|` left between the first two fields.
+//! const LAYOUT: [Field<u32>; 3] = [
+//!     Field::new(0, 6, b'0', 10),
+//!     Field::new(7, 8, b' ', 10),
+//!     Field::new(15, 2, b'0', 16),
+//! ];
+//!
+//! let mut record = *b"      |          ";
+//! encode_record(&LAYOUT, &[4711u32, 250_000, 0x1F], &mut record);
+//! assert_eq!(&record, b"004711|  2500001F");
+//! # }
+//! ```
+
+use super::frame::write_padded;
+use super::NumToA;
+
+/// The position and formatting of a numeric field within a record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Field<B> {
+    /// The indice of the first byte of the field.
+    pub offset: usize,
+    /// The number of bytes which the field occupies.
+    pub width: usize,
+    /// The byte which the field is padded with on the left, such as `b'0'` or `b' '`.
+    pub pad: u8,
+    /// The base which the number is written in.
+    pub base: B,
+}
+
+impl<B> Field<B> {
+    /// Declares a field of `width` bytes beginning at `offset`.
+    pub const fn new(offset: usize, width: usize, pad: u8, base: B) -> Self {
+        Field { offset, width, pad, base }
+    }
+}
+
+/// Writes each number into its field of the record, right-aligned and padded to the width of the field. The bytes
+/// of the record which do not belong to any field are left untouched.
+///
+/// # Panics
+/// If there is not a field for every number, if a field extends past the end of the record, or if a number has
+/// more digits than the width of its field, this will panic.
+pub fn encode_record<T: NumToA<B> + Copy, B: Copy>(fields: &[Field<B>], values: &[T], record: &mut [u8]) {
+    assert!(fields.len() == values.len(), "numtoa: expected a field for every number");
+    for (field, &value) in fields.iter().zip(values) {
+        let bytes = record.get_mut(field.offset..field.offset + field.width).expect("numtoa: buffer is too small");
+        write_padded(bytes, value, field.base, field.pad);
+    }
+}
+
+#[test]
+#[cfg(feature = "i16")]
+fn record_fields() {
+    let layout = [Field::new(4, 4, b' ', 10i16), Field::new(0, 4, b'0', 2i16)];
+    let mut record = [b'.'; 9];
+    encode_record(&layout, &[-12i16, 5], &mut record);
+    assert_eq!(&record, b"0101 -12.");
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "i16")]
+fn record_past_end() {
+    let mut record = [0u8; 4];
+    encode_record(&[Field::new(2, 3, b'0', 10i16)], &[1i16], &mut record);
+}
@@ -0,0 +1,75 @@
+//! The numeric lines of RESP, the protocol spoken by Redis: integer replies and the length headers of bulk strings
+//! and arrays.
+//!
+//! ```
+//! use numtoa::resp::{format_bulk_len, format_resp_integer};
+//!
+//! let mut buffer = [0u8; 24];
+//! let start = format_resp_integer(1000, &mut buffer);
+//! assert_eq!(&buffer[start..], b":1000\r\n");
+//!
+//! let start = format_bulk_len(Some(5), &mut buffer);
+//! assert_eq!(&buffer[start..], b"$5\r\n");
+//! ```
+
+use super::rtl::Rtl;
+
+// Writes the type byte, the value, and CRLF, which is the layout of every numeric line.
+fn format_line(prefix: u8, value: i64, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(b"\r\n");
+    rtl.digits(u128::from(value.unsigned_abs()));
+    if value < 0 {
+        rtl.byte(b'-');
+    }
+    rtl.byte(prefix);
+    rtl.index()
+}
+
+// Lengths are written as -1 when absent, which is how RESP encodes a null.
+fn length(len: Option<usize>) -> i64 {
+    len.map_or(-1, |len| len as i64)
+}
+
+/// Writes an integer reply, such as `:1000` followed by CRLF, into the end of the byte slice. Returns the indice
+/// where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, which is at most 23 bytes, this will panic.
+pub fn format_resp_integer(value: i64, string: &mut [u8]) -> usize {
+    format_line(b':', value, string)
+}
+
+/// Writes the header of a bulk string of `len` bytes, such as `$5` followed by CRLF, into the end of the byte
+/// slice. A length of `None` writes the null bulk string, `$-1`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_bulk_len(len: Option<usize>, string: &mut [u8]) -> usize {
+    format_line(b'$', length(len), string)
+}
+
+/// Writes the header of an array of `len` elements, such as `*2` followed by CRLF, into the end of the byte
+/// slice. A length of `None` writes the null array, `*-1`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_array_len(len: Option<usize>, string: &mut [u8]) -> usize {
+    format_line(b'*', length(len), string)
+}
+
+#[test]
+fn resp_lines() {
+    let mut buffer = [0u8; 23];
+    let i = format_resp_integer(i64::MIN, &mut buffer);
+    assert_eq!(&buffer[i..], b":-9223372036854775808\r\n");
+
+    let i = format_resp_integer(0, &mut buffer);
+    assert_eq!(&buffer[i..], b":0\r\n");
+
+    let i = format_bulk_len(None, &mut buffer);
+    assert_eq!(&buffer[i..], b"$-1\r\n");
+
+    let i = format_array_len(Some(0), &mut buffer);
+    assert_eq!(&buffer[i..], b"*0\r\n");
+}
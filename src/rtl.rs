@@ -0,0 +1,144 @@
+// A cursor which writes into a byte slice from right to left, as every conversion in this crate does. The
+// higher level formatters are composed from these pieces, writing their suffixes first and their leading
+// signs last, so that the output is always a contiguous string which ends at the end of the buffer.
+
+use super::{DEC_LOOKUP, HEX_LOOKUP_UPPER, LOOKUP_UPPER};
+
+// Large enough for any primitive integer in any base, which is an `i128` in base 2 with its sign. Every module
+// which converts through `NumToA` into a scratch buffer sizes it with this.
+pub(crate) const SCRATCH_LEN: usize = 129;
+
+pub(crate) struct Rtl<'a> {
+    string: &'a mut [u8],
+    index: usize,
+}
+
+impl<'a> Rtl<'a> {
+    pub(crate) fn new(string: &'a mut [u8]) -> Self {
+        let index = string.len();
+        Rtl { string, index }
+    }
+
+    // The indice where the written string begins.
+    pub(crate) fn index(&self) -> usize { self.index }
+
+    pub(crate) fn byte(&mut self, byte: u8) {
+        self.index = self.index.checked_sub(1).expect("numtoa: buffer is too small");
+        self.string[self.index] = byte;
+    }
+
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) {
+        self.index = self.index.checked_sub(bytes.len()).expect("numtoa: buffer is too small");
+        self.string[self.index..self.index + bytes.len()].copy_from_slice(bytes);
+    }
+
+    // Writes the character as UTF-8.
+    pub(crate) fn char(&mut self, c: char) {
+        let mut utf8 = [0u8; 4];
+        self.bytes(c.encode_utf8(&mut utf8).as_bytes());
+    }
+
+    // Moves the cursor left by `width` bytes, returning them to be filled by the caller.
+    pub(crate) fn reserve(&mut self, width: usize) -> &mut [u8] {
+        let end = self.index;
+        self.index = self.index.checked_sub(width).expect("numtoa: buffer is too small");
+        &mut self.string[self.index..end]
+    }
+
+    // Writes the byte as two upper case hexadecimal digits.
+    pub(crate) fn hex_byte(&mut self, byte: u8) {
+        let pair = byte as usize * 2;
+        self.bytes(&HEX_LOOKUP_UPPER[pair..pair + 2]);
+    }
+
+    // Writes the upper case hexadecimal digits of the number, with at least one digit.
+    pub(crate) fn hex(&mut self, mut number: u128) {
+        loop {
+            self.byte(LOOKUP_UPPER[(number & 0xF) as usize]);
+            number >>= 4;
+            if number == 0 {
+                break;
+            }
+        }
+    }
+
+    // Writes the upper case hexadecimal digits of the number, padded on the left with zeros up to `width` digits.
+    pub(crate) fn hex_padded(&mut self, number: u128, width: usize) {
+        let end = self.index;
+        self.hex(number);
+        while end - self.index < width {
+            self.byte(b'0');
+        }
+    }
+
+    // Writes the decimal digits of the number, with at least one digit.
+    pub(crate) fn digits(&mut self, mut number: u128) {
+        while number >= 100 {
+            let pair = (number % 100) as usize * 2;
+            self.bytes(&DEC_LOOKUP[pair..pair + 2]);
+            number /= 100;
+        }
+
+        if number >= 10 {
+            let pair = number as usize * 2;
+            self.bytes(&DEC_LOOKUP[pair..pair + 2]);
+        } else {
+            self.byte(b'0' + number as u8);
+        }
+    }
+
+    // Writes the decimal digits of the number, padded on the left with `pad` up to `width` bytes.
+    pub(crate) fn digits_padded(&mut self, number: u128, width: usize, pad: u8) {
+        let end = self.index;
+        self.digits(number);
+        while end - self.index < width {
+            self.byte(pad);
+        }
+    }
+
+    // Writes a magnitude with an implied number of fractional digits, such as `3300` with three being `3.300`.
+    pub(crate) fn fixed(&mut self, magnitude: u128, decimals: u32) {
+        self.fixed_padded(magnitude, decimals, 1);
+    }
+
+    // Writes a magnitude as `fixed` does, with the whole part padded on the left with zeros up to `width` digits.
+    pub(crate) fn fixed_padded(&mut self, magnitude: u128, decimals: u32, width: usize) {
+        // A divisor too large for a `u128` is above every magnitude, which leaves the whole part at zero.
+        let (whole, fraction) = match 10u128.checked_pow(decimals) {
+            Some(divisor) => (magnitude / divisor, magnitude % divisor),
+            None          => (0, magnitude),
+        };
+
+        if decimals != 0 {
+            self.digits_padded(fraction, decimals as usize, b'0');
+            self.byte(b'.');
+        }
+        self.digits_padded(whole, width, b'0');
+    }
+}
+
+#[test]
+fn rtl_composition() {
+    let mut buffer = [0u8; 32];
+    let mut rtl = Rtl::new(&mut buffer);
+    rtl.bytes(b" V");
+    rtl.fixed(3300, 3);
+    rtl.byte(b'-');
+    let i = rtl.index();
+    assert_eq!(&buffer[i..], b"-3.300 V");
+
+    let mut rtl = Rtl::new(&mut buffer);
+    rtl.digits_padded(7, 3, b' ');
+    rtl.digits(1_000_000_000_000_000_000_000);
+    let i = rtl.index();
+    assert_eq!(&buffer[i..], b"1000000000000000000000  7");
+}
+
+#[test]
+fn rtl_fixed_beyond_u128() {
+    let mut buffer = [0u8; 48];
+    let mut rtl = Rtl::new(&mut buffer);
+    rtl.fixed(12, 40);
+    let i = rtl.index();
+    assert_eq!(&buffer[i..], b"0.0000000000000000000000000000000000000012");
+}
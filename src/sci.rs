@@ -0,0 +1,169 @@
+//! Scientific and engineering notation for scaled integers, with control over how the exponent is written so
+//! that output can satisfy strict downstream parsers.
+//!
+//! ```
+//! use numtoa::sci::{format_sci, ExponentFormat, Notation};
+//!
+//! let format = ExponentFormat { force_sign: true, min_digits: 2, ..ExponentFormat::default() };
+//! let mut buffer = [0u8; 32];
+//! let start = format_sci(1500, 0, 1, Notation::Scientific, format, &mut buffer);
+//! assert_eq!(&buffer[start..], b"1.5e+03");
+//! ```
+
+use core::convert::TryFrom;
+
+use super::fixed::{convert, Rounding};
+use super::rtl::Rtl;
+
+/// Selects how the exponent of a number is chosen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// The mantissa has exactly one integer digit, such as `1.5e3`.
+    #[default]
+    Scientific,
+    /// The exponent is a multiple of three, so the mantissa has one to three integer digits, such as `15e3`.
+    Engineering,
+}
+
+/// Controls how the exponent is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExponentFormat {
+    /// Writes `E` rather than `e`.
+    pub uppercase: bool,
+    /// Writes a `+` before exponents which are not negative.
+    pub force_sign: bool,
+    /// Pads the exponent with zeros to at least this many digits.
+    pub min_digits: u8,
+}
+
+impl Default for ExponentFormat {
+    fn default() -> Self {
+        ExponentFormat { uppercase: false, force_sign: false, min_digits: 1 }
+    }
+}
+
+// The number of decimal digits in a value, where zero has one digit.
+fn decimal_digits(mut value: u128) -> u32 {
+    let mut digits = 1;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+// Divides by a power of ten when the shift is positive, and multiplies when it is negative.
+fn shift_rounded(magnitude: u128, shift: i64) -> u128 {
+    if shift < 0 {
+        return convert(magnitude, 1, 1, shift.unsigned_abs() as u32, Rounding::HalfUp);
+    }
+
+    // Every `i64` magnitude is below half of a divisor too large for a `u128`, so it rounds to zero.
+    u32::try_from(shift).ok().and_then(|shift| 10u128.checked_pow(shift))
+        .map_or(0, |divisor| Rounding::HalfUp.div(magnitude, divisor))
+}
+
+/// Writes `value / 10^scale` into the end of the byte slice in the given notation with `decimals` fractional
+/// digits in the mantissa, which is rounded half up. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If `decimals` is above 37 in scientific notation or above 35 in engineering notation, where the mantissa no
+/// longer fits in a `u128`, or the supplied buffer is too small to hold the output, this will panic.
+pub fn format_sci(value: i64, scale: u32, decimals: u32, notation: Notation, format: ExponentFormat,
+    string: &mut [u8]) -> usize
+{
+    let magnitude = u128::from(value.unsigned_abs());
+    let span: i64 = if let Notation::Engineering = notation { 3 } else { 1 };
+
+    // The mantissa scaled by `10^decimals` stays below `10^(span + decimals)`, which bounds every shift below.
+    let limit = decimals.checked_add(span as u32)
+        .and_then(|digits| 10u128.checked_pow(digits))
+        .expect("numtoa: too many decimals for the mantissa");
+
+    // The exponent of the most significant digit, rounded down to the notation's step.
+    let top = i64::from(decimal_digits(magnitude)) - 1;
+    let mut exponent = if magnitude == 0 { 0 } else { (top - i64::from(scale)).div_euclid(span) * span };
+
+    // The mantissa scaled by `10^decimals` is `magnitude * 10^decimals / 10^(scale + exponent)`.
+    let offset = i64::from(scale) - i64::from(decimals);
+    let mut mantissa = shift_rounded(magnitude, offset + exponent);
+    if mantissa >= limit {
+        exponent += span;
+        mantissa = shift_rounded(magnitude, offset + exponent);
+    }
+
+    let mut rtl = Rtl::new(string);
+    rtl.digits_padded(u128::from(exponent.unsigned_abs()), usize::from(format.min_digits), b'0');
+    if exponent < 0 {
+        rtl.byte(b'-');
+    } else if format.force_sign {
+        rtl.byte(b'+');
+    }
+    rtl.byte(if format.uppercase { b'E' } else { b'e' });
+    rtl.fixed(mantissa, decimals);
+    if value < 0 {
+        rtl.byte(b'-');
+    }
+    rtl.index()
+}
+
+#[test]
+fn scientific_notation() {
+    let mut buffer = [0u8; 48];
+    let default = ExponentFormat::default();
+    let i = format_sci(0, 0, 2, Notation::Scientific, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.00e0");
+
+    let i = format_sci(-123456, 3, 2, Notation::Scientific, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"-1.23e2");
+
+    let i = format_sci(99_960, 0, 2, Notation::Scientific, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"1.00e5");
+
+    let format = ExponentFormat { uppercase: true, force_sign: true, min_digits: 3 };
+    let i = format_sci(5, 4, 1, Notation::Scientific, format, &mut buffer);
+    assert_eq!(&buffer[i..], b"5.0E-004");
+
+    let i = format_sci(i64::MAX, 0, 3, Notation::Scientific, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"9.223e18");
+}
+
+#[test]
+fn engineering_notation() {
+    let mut buffer = [0u8; 32];
+    let default = ExponentFormat::default();
+    let i = format_sci(15_000, 0, 1, Notation::Engineering, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"15.0e3");
+
+    let i = format_sci(5, 1, 0, Notation::Engineering, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"500e-3");
+
+    let i = format_sci(999_999, 0, 1, Notation::Engineering, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"1.0e6");
+}
+
+#[test]
+fn sci_limits() {
+    let mut buffer = [0u8; 64];
+    let default = ExponentFormat::default();
+    let i = format_sci(9, 0, 37, Notation::Scientific, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"9.0000000000000000000000000000000000000e0");
+
+    let i = format_sci(i64::MIN, 0, 37, Notation::Scientific, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"-9.2233720368547758080000000000000000000e18");
+
+    let i = format_sci(999, 0, 35, Notation::Engineering, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"999.00000000000000000000000000000000000e0");
+
+    let i = format_sci(1, u32::MAX, 2, Notation::Scientific, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"1.00e-4294967295");
+
+    let i = format_sci(0, u32::MAX, 2, Notation::Engineering, default, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.00e0");
+}
+
+#[test]
+#[should_panic(expected = "numtoa: too many decimals for the mantissa")]
+fn sci_too_many_decimals() {
+    format_sci(9, 0, 36, Notation::Engineering, ExponentFormat::default(), &mut [0u8; 64]);
+}
@@ -0,0 +1,121 @@
+//! Output for segment displays, where each digit is written as the bitmask of the segments which light it up
+//! rather than as ASCII, so that display drivers can write the result straight into their registers.
+//!
+//! The segments are named clockwise from the top, `a` through `f`, followed by the middle bar `g`. The order maps
+//! each segment to the bit which drives it, since every display controller wires them differently.
+//!
+//! ```
+//! # #[cfg(feature = "i16")] {
+//! use numtoa::segment::{numtoa_seven_segment, SEVEN_SEGMENT_ORDER};
+//!
+//! let mut buffer = [0u8; 6];
+//! let start = numtoa_seven_segment(-42i16, 10, &SEVEN_SEGMENT_ORDER, &mut buffer);
+//! assert_eq!(&buffer[start..], &[0x40, 0x66, 0x5B]);
+//! # }
+//! ```
+
+use super::rtl::SCRATCH_LEN;
+use super::NumToA;
+
+/// The order of a display which drives the segments `a` through `g` with the bits 0 through 6.
+pub const SEVEN_SEGMENT_ORDER: [u8; 7] = [0, 1, 2, 3, 4, 5, 6];
+
+/// The order of a display which drives the segments `a` through `f`, `g1`, `g2`, `h`, `j`, `k`, `l`, `m`, and `n`
+/// with the bits 0 through 13, as the common HT16K33 backpacks do.
+pub const FOURTEEN_SEGMENT_ORDER: [u8; 14] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+
+// The segments of the digits 0 through F, with bit `n` lighting segment `n` of the standard order.
+const SEVEN_SEGMENT_DIGITS: [u8; 16] = [
+    0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F, 0x77, 0x7C, 0x39, 0x5E, 0x79, 0x71,
+];
+const FOURTEEN_SEGMENT_DIGITS: [u16; 16] = [
+    0x0C3F, 0x0006, 0x00DB, 0x008F, 0x00E6, 0x00ED, 0x00FD, 0x0007,
+    0x00FF, 0x00EF, 0x00F7, 0x128F, 0x0039, 0x120F, 0x00F9, 0x0071,
+];
+
+// Looks up the segments of an ASCII digit or sign, in the standard order.
+fn segments(byte: u8, digits: &[u16; 16], minus: u16) -> u16 {
+    match byte {
+        b'0'..=b'9' => digits[usize::from(byte - b'0')],
+        b'A'..=b'F' => digits[usize::from(byte - b'A' + 10)],
+        b'-' => minus,
+        _ => panic!("numtoa: segment displays only show bases up to 16"),
+    }
+}
+
+// Moves each segment from its bit in the standard order to its bit in the given order.
+fn reorder(pattern: u16, order: &[u8]) -> u16 {
+    order
+        .iter()
+        .enumerate()
+        .filter(|&(segment, _)| pattern & 1 << segment != 0)
+        .fold(0, |out, (_, &bit)| out | 1 << bit)
+}
+
+/// Writes the number in the given base into the end of the slice as seven-segment patterns, one byte per digit
+/// with a leading minus drawn with segment `g`, returning the indice where the patterns begin. Bases above 10
+/// use the shapes `A b C d E F`.
+///
+/// # Panics
+/// If the base is above 16, if the order maps a segment past bit 7, or if the supplied buffer is too small to hold
+/// the digits, this will panic.
+pub fn numtoa_seven_segment<T: NumToA<B>, B>(number: T, base: B, order: &[u8; 7], string: &mut [u8]) -> usize {
+    assert!(order.iter().all(|&bit| bit < 8), "numtoa: segment bit out of range");
+    let mut scratch = [0u8; SCRATCH_LEN];
+    let start = number.numtoa(base, &mut scratch);
+    let digits = &scratch[start..];
+    let begin = string.len().checked_sub(digits.len()).expect("numtoa: buffer is too small");
+
+    let table = SEVEN_SEGMENT_DIGITS.map(u16::from);
+    for (out, &byte) in string[begin..].iter_mut().zip(digits) {
+        *out = reorder(segments(byte, &table, 0x40), order) as u8;
+    }
+    begin
+}
+
+/// Writes the number in the given base into the end of the slice as fourteen-segment patterns, one word per digit
+/// with a leading minus drawn with segments `g1` and `g2`, returning the indice where the patterns begin.
+///
+/// # Panics
+/// If the base is above 16, if the order maps a segment past bit 15, or if the supplied buffer is too small to
+/// hold the digits, this will panic.
+pub fn numtoa_fourteen_segment<T: NumToA<B>, B>(number: T, base: B, order: &[u8; 14], string: &mut [u16]) -> usize {
+    assert!(order.iter().all(|&bit| bit < 16), "numtoa: segment bit out of range");
+    let mut scratch = [0u8; SCRATCH_LEN];
+    let start = number.numtoa(base, &mut scratch);
+    let digits = &scratch[start..];
+    let begin = string.len().checked_sub(digits.len()).expect("numtoa: buffer is too small");
+
+    for (out, &byte) in string[begin..].iter_mut().zip(digits) {
+        *out = reorder(segments(byte, &FOURTEEN_SEGMENT_DIGITS, 0x00C0), order);
+    }
+    begin
+}
+
+#[test]
+#[cfg(feature = "u8")]
+fn seven_segments() {
+    let mut buffer = [0u8; 8];
+    let i = numtoa_seven_segment(0xEFu8, 16, &SEVEN_SEGMENT_ORDER, &mut buffer);
+    assert_eq!(&buffer[i..], &[0x79, 0x71]);
+
+    // A controller wired with segment `a` on the highest bit, down to `g` on bit 1.
+    let i = numtoa_seven_segment(1u8, 10, &[7, 6, 5, 4, 3, 2, 1], &mut buffer);
+    assert_eq!(&buffer[i..], &[0b0110_0000]);
+}
+
+#[test]
+#[cfg(feature = "i8")]
+fn fourteen_segments() {
+    let mut buffer = [0u16; 8];
+    let i = numtoa_fourteen_segment(-8i8, 10, &FOURTEEN_SEGMENT_ORDER, &mut buffer);
+    assert_eq!(&buffer[i..], &[0x00C0, 0x00FF]);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "u8")]
+fn segments_above_hex() {
+    let mut buffer = [0u8; 8];
+    numtoa_seven_segment(35u8, 36, &SEVEN_SEGMENT_ORDER, &mut buffer);
+}
@@ -0,0 +1,155 @@
+//! Exhaustive conformance checks of the conversions of the small integer types, which compare every value in
+//! every base against a plain reference implementation. Teams shipping firmware can run them on their own
+//! hardware, such as from a hardware-in-the-loop rig, to verify the crate as it was compiled for their target.
+//!
+//! Each value is converted through `NumToA::numtoa`, the non-generic `write_*` function, and, in base 10, the
+//! `write_*_base10` fast path. Every conversion must match the reference, must leave the bytes before its output
+//! untouched, and must parse back to the same value through `FromRadix` in both upper and lower case.
+//!
+//! ```
+//! assert_eq!(numtoa::selftest::verify_u8(), Ok(()));
+//! ```
+
+use core::fmt;
+
+use super::radix::FromRadix;
+use super::{write_i16, write_i16_base10, write_i8, write_i8_base10, write_u16, write_u16_base10, write_u8};
+use super::{write_u8_base10, NumToA};
+
+// Large enough for an `i16` in base 2 with its sign, with bytes to spare which must remain untouched.
+const BUFFER_LEN: usize = 24;
+const SENTINEL: u8 = 0xA5;
+
+/// The path through which a value was converted or checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Check {
+    /// The generic `NumToA::numtoa`.
+    Trait,
+    /// The non-generic `write_*` function.
+    Write,
+    /// The decimal fast path of the `write_*_base10` function.
+    Base10,
+    /// Parsing the output back through `FromRadix`, in upper or lower case.
+    RoundTrip,
+}
+
+/// A conversion which did not agree with the reference implementation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mismatch {
+    /// The value which was converted, widened to an `i32`.
+    pub value: i32,
+    /// The base which the value was converted in.
+    pub base: u32,
+    /// The path which disagreed.
+    pub check: Check,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} conversion of {} in base {} does not match the reference", self.check, self.value, self.base)
+    }
+}
+
+// Writes the value in the given base into the end of the buffer by repeated division, with upper case letters,
+// returning the indice where the digits begin.
+fn reference(value: i32, base: u32, string: &mut [u8; BUFFER_LEN]) -> usize {
+    let mut index = string.len();
+    let mut rest = value.unsigned_abs();
+    loop {
+        let digit = (rest % base) as u8;
+        index -= 1;
+        string[index] = if digit < 10 { b'0' + digit } else { b'A' + digit - 10 };
+        rest /= base;
+        if rest == 0 {
+            break;
+        }
+    }
+
+    if value < 0 {
+        index -= 1;
+        string[index] = b'-';
+    }
+    index
+}
+
+// Runs one conversion into a buffer of sentinel bytes, and compares it with the reference.
+fn check(value: i32, base: u32, check: Check, convert: impl FnOnce(&mut [u8]) -> usize) -> Result<(), Mismatch> {
+    let mut expected = [0u8; BUFFER_LEN];
+    let expected_start = reference(value, base, &mut expected);
+
+    let mut buffer = [SENTINEL; BUFFER_LEN];
+    let start = convert(&mut buffer);
+    let untouched = buffer[..start].iter().all(|&byte| byte == SENTINEL);
+    if untouched && buffer[start..] == expected[expected_start..] {
+        Ok(())
+    } else {
+        Err(Mismatch { value, base, check })
+    }
+}
+
+// Parses the reference output back in both upper and lower case.
+fn round_trip<T: FromRadix + PartialEq>(value: T, widened: i32, base: u32) -> Result<(), Mismatch> {
+    let mut digits = [0u8; BUFFER_LEN];
+    let start = reference(widened, base, &mut digits);
+    let upper = T::from_radix(&digits[start..], base);
+    digits.make_ascii_lowercase();
+    let lower = T::from_radix(&digits[start..], base);
+
+    match (upper, lower) {
+        (Ok(upper), Ok(lower)) if upper == value && lower == value => Ok(()),
+        _ => Err(Mismatch { value: widened, base, check: Check::RoundTrip }),
+    }
+}
+
+macro_rules! verify {
+    ($name:ident, $t:ty, $write:ident, $write_base10:ident) => {
+        #[doc = concat!("Verifies every `", stringify!($t), "` in every base from 2 to 36, returning the first ")]
+        #[doc = "conversion which disagrees with the reference."]
+        pub fn $name() -> Result<(), Mismatch> {
+            for value in <$t>::MIN..=<$t>::MAX {
+                let widened = i32::from(value);
+                for base in 2..=36 {
+                    check(widened, base, Check::Trait, |string| value.numtoa(base as $t, string))?;
+                    check(widened, base, Check::Write, |string| $write(value, base as $t, string))?;
+                    round_trip(value, widened, base)?;
+                }
+                check(widened, 10, Check::Base10, |string| $write_base10(value, string))?;
+            }
+            Ok(())
+        }
+    };
+}
+
+verify!(verify_u8, u8, write_u8, write_u8_base10);
+verify!(verify_i8, i8, write_i8, write_i8_base10);
+verify!(verify_u16, u16, write_u16, write_u16_base10);
+verify!(verify_i16, i16, write_i16, write_i16_base10);
+
+/// Runs every verification in turn, returning the first conversion which disagrees with the reference.
+pub fn verify_all() -> Result<(), Mismatch> {
+    verify_u8()?;
+    verify_i8()?;
+    verify_u16()?;
+    verify_i16()
+}
+
+#[test]
+fn self_test() {
+    assert_eq!(verify_all(), Ok(()));
+}
+
+#[test]
+fn mismatches_are_caught() {
+    let wrong = check(-5, 10, Check::Trait, |string| {
+        string[string.len() - 1] = b'5';
+        string.len() - 1
+    });
+    assert_eq!(wrong, Err(Mismatch { value: -5, base: 10, check: Check::Trait }));
+
+    let stray = check(7, 10, Check::Write, |string| {
+        string[0] = 0;
+        string[string.len() - 1] = b'7';
+        string.len() - 1
+    });
+    assert!(stray.is_err());
+}
@@ -0,0 +1,66 @@
+//! Presets for the logs of storage firmware and bootloaders, which show block addresses and byte offsets in
+//! decimal and in fixed-width hexadecimal side by side.
+//!
+//! ```
+//! use numtoa::storage::format_lba;
+//!
+//! let mut buffer = [0u8; 40];
+//! let start = format_lba(123_456, &mut buffer);
+//! assert_eq!(&buffer[start..], b"LBA 123456 (0x0001E240)");
+//! ```
+
+use super::rtl::Rtl;
+
+/// Writes the value into the end of the byte slice after the label, in decimal and then in parentheses as `0x`
+/// prefixed upper case hexadecimal, padded with zeros to at least `hex_digits` digits, such as
+/// `sector 2048 (0x00000800)`. An empty label is left out along with its space. Returns the indice where the
+/// inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_dec_hex(label: &[u8], value: u64, hex_digits: usize, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    rtl.byte(b')');
+    rtl.hex_padded(u128::from(value), hex_digits);
+    rtl.bytes(b" (0x");
+    rtl.digits(u128::from(value));
+    if !label.is_empty() {
+        rtl.byte(b' ');
+        rtl.bytes(label);
+    }
+    rtl.index()
+}
+
+/// Writes a logical block address as `LBA 123456 (0x0001E240)`, with at least eight hexadecimal digits. Returns
+/// the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_lba(lba: u64, string: &mut [u8]) -> usize {
+    format_dec_hex(b"LBA", lba, 8, string)
+}
+
+/// Writes a byte offset as `offset 4096 (0x00001000)`, with at least eight hexadecimal digits. Returns the indice
+/// where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_offset(offset: u64, string: &mut [u8]) -> usize {
+    format_dec_hex(b"offset", offset, 8, string)
+}
+
+#[test]
+fn storage_presets() {
+    let mut buffer = [0u8; 64];
+    let i = format_lba(0, &mut buffer);
+    assert_eq!(&buffer[i..], b"LBA 0 (0x00000000)");
+
+    let i = format_lba(0x1_0000_0000, &mut buffer);
+    assert_eq!(&buffer[i..], b"LBA 4294967296 (0x100000000)");
+
+    let i = format_offset(4096, &mut buffer);
+    assert_eq!(&buffer[i..], b"offset 4096 (0x00001000)");
+
+    let i = format_dec_hex(b"", 255, 4, &mut buffer);
+    assert_eq!(&buffer[i..], b"255 (0x00FF)");
+}
@@ -0,0 +1,57 @@
+//! Formatting of table rows for serial consoles, where each number is right-aligned within a column of fixed width.
+//!
+//! ```
+//! # #[cfg(feature = "u32")] {
+//! use numtoa::table::format_row;
+//!
+//! // The task id, priority, and free stack of a task list.
+//! let mut buffer = [0u8; 32];
+//! let start = format_row(&[7u32, 24, 1536], 10, &[3, 5, 8], &mut buffer);
+//! assert_eq!(&buffer[start..], b"  7   24    1536");
+//! # }
+//! ```
+
+use super::rtl::{Rtl, SCRATCH_LEN};
+use super::NumToA;
+
+/// Writes a row of numbers into the end of the byte slice, with each number right-aligned and padded with spaces
+/// to the width of its column. A number which does not fit within its column is written as a column of `#`, so
+/// that the columns which follow it stay aligned. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If there is not a width for every number, or if the supplied buffer is smaller than the sum of the widths,
+/// this will panic.
+pub fn format_row<T: NumToA<B> + Copy, B: Copy>(values: &[T], base: B, widths: &[usize], string: &mut [u8]) -> usize {
+    assert!(values.len() == widths.len(), "numtoa: expected a width for every column");
+
+    let mut rtl = Rtl::new(string);
+    let mut scratch = [0u8; SCRATCH_LEN];
+    for (&value, &width) in values.iter().zip(widths).rev() {
+        let start = value.numtoa(base, &mut scratch);
+        let digits = &scratch[start..];
+        if digits.len() > width {
+            (0..width).for_each(|_| rtl.byte(b'#'));
+        } else {
+            rtl.bytes(digits);
+            (digits.len()..width).for_each(|_| rtl.byte(b' '));
+        }
+    }
+    rtl.index()
+}
+
+#[test]
+#[cfg(feature = "i16")]
+fn table_rows() {
+    let mut buffer = [0u8; 24];
+    let i = format_row(&[-1i16, 0, 32767], 10, &[4, 2, 6], &mut buffer);
+    assert_eq!(&buffer[i..], b"  -1 0 32767");
+
+    let i = format_row(&[100i16, 5], 10, &[2, 3], &mut buffer);
+    assert_eq!(&buffer[i..], b"##  5");
+
+    let i = format_row(&[255i16], 16, &[2], &mut buffer);
+    assert_eq!(&buffer[i..], b"FF");
+
+    let i = format_row::<i16, i16>(&[], 10, &[], &mut buffer);
+    assert_eq!(&buffer[i..], b"");
+}
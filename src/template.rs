@@ -0,0 +1,160 @@
+//! Patching of numbers into messages which were formatted once ahead of time, so that a device streaming events
+//! rewrites only the digits which change rather than serializing the whole message again.
+//!
+//! ```
+//! use numtoa::template::patch_id;
+//!
+//! // A JSON-RPC request with room for an id of up to six digits.
+//! let mut message = *br#"{"jsonrpc":"2.0","method":"tick","id":      }"#;
+//! let offset = message.len() - 7;
+//! for id in 1..=12 {
+//!     patch_id(&mut message, offset, 6, id);
+//! }
+//! assert_eq!(&message, br#"{"jsonrpc":"2.0","method":"tick","id":12    }"#);
+//! ```
+//!
+//! Any number can be patched into a field with `patch_field`, aligned and padded as the format requires.
+//!
+//! ```
+//! # #[cfg(feature = "i16")] {
+//! use numtoa::template::{patch_field, Align};
+//!
+//! let mut line = *b"T=+000.0C";
+//! patch_field(&mut line, 3, 3, 21i16, 10, Align::Right, b'0');
+//! assert_eq!(&line, b"T=+021.0C");
+//! # }
+//! ```
+
+use super::rtl::{Rtl, SCRATCH_LEN};
+use super::NumToA;
+
+/// The side of a field which a number is aligned to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Writes the number at the start of the field, with the padding after it.
+    Left,
+    /// Writes the number at the end of the field, with the padding before it.
+    #[default]
+    Right,
+}
+
+// Fills the field with the digits, aligned to one side and padded with `pad` on the other. Zeros which pad a
+// negative number on the left go between its sign and its digits, as `-0005` rather than `000-5`.
+pub(crate) fn fill_field(field: &mut [u8], digits: &[u8], align: Align, pad: u8) {
+    assert!(digits.len() <= field.len(), "numtoa: the number is wider than its field");
+    let (field, digits) = match digits.split_first() {
+        Some((&b'-', rest)) if align == Align::Right && pad == b'0' => {
+            field[0] = b'-';
+            (&mut field[1..], rest)
+        }
+        _ => (field, digits),
+    };
+
+    let (value, padding) = match align {
+        Align::Left => field.split_at_mut(digits.len()),
+        Align::Right => {
+            let (padding, value) = field.split_at_mut(field.len() - digits.len());
+            (value, padding)
+        }
+    };
+    value.copy_from_slice(digits);
+    padding.iter_mut().for_each(|byte| *byte = pad);
+}
+
+/// Overwrites the field of `width` bytes at `offset` within the template with the number in the given base,
+/// aligned to one side of the field and padded with `pad` on the other. The rest of the template is untouched.
+///
+/// # Panics
+/// If the field extends past the end of the template, or the number has more digits than the width of the
+/// field, this will panic.
+pub fn patch_field<T: NumToA<B>, B>(
+    template: &mut [u8],
+    offset: usize,
+    width: usize,
+    number: T,
+    base: B,
+    align: Align,
+    pad: u8,
+) {
+    let field = template.get_mut(offset..offset + width).expect("numtoa: buffer is too small");
+    let mut scratch = [0u8; SCRATCH_LEN];
+    let start = number.numtoa(base, &mut scratch);
+    fill_field(field, &scratch[start..], align, pad);
+}
+
+/// Overwrites the field of `width` bytes at `offset` within a JSON template with the id, left-aligned and padded on
+/// the right with spaces. Trailing whitespace is insignificant in JSON, so the rest of the message stays valid.
+///
+/// # Panics
+/// If the field extends past the end of the template, or the id has more digits than the width of the field,
+/// this will panic.
+pub fn patch_id(template: &mut [u8], offset: usize, width: usize, id: u64) {
+    patch_id_aligned(template, offset, width, id, Align::Left, b' ');
+}
+
+/// Overwrites the field of `width` bytes at `offset` within a server-sent event template with the id, padded on
+/// the left with zeros. An SSE `id:` field keeps every byte after its colon, so padding with spaces would change
+/// the id, whereas zeros keep it a fixed-width decimal which parses back to the same number.
+///
+/// # Panics
+/// If the field extends past the end of the template, or the id has more digits than the width of the field,
+/// this will panic.
+pub fn patch_sse_id(template: &mut [u8], offset: usize, width: usize, id: u64) {
+    patch_id_aligned(template, offset, width, id, Align::Right, b'0');
+}
+
+// Writes the decimal digits of the id into the field, aligned to one side and padded with `pad` on the other.
+fn patch_id_aligned(template: &mut [u8], offset: usize, width: usize, id: u64, align: Align, pad: u8) {
+    let field = template.get_mut(offset..offset + width).expect("numtoa: buffer is too small");
+    let mut scratch = [0u8; 20];
+    let mut rtl = Rtl::new(&mut scratch);
+    rtl.digits(u128::from(id));
+    let start = rtl.index();
+    fill_field(field, &scratch[start..], align, pad);
+}
+
+#[test]
+fn patched_ids() {
+    let mut message = *br#"{"id":XXXX}"#;
+    patch_id(&mut message, 6, 4, 9999);
+    assert_eq!(&message, br#"{"id":9999}"#);
+
+    patch_id(&mut message, 6, 4, 0);
+    assert_eq!(&message, br#"{"id":0   }"#);
+
+    let mut event = *b"id: XXXX\ndata: {}\n\n";
+    patch_sse_id(&mut event, 4, 4, 42);
+    assert_eq!(&event, b"id: 0042\ndata: {}\n\n");
+}
+
+#[test]
+#[cfg(feature = "u32")]
+fn patched_fields() {
+    let mut frame = *b"[........]";
+    patch_field(&mut frame, 1, 8, 0xBEEFu32, 16, Align::Right, b'0');
+    assert_eq!(&frame, b"[0000BEEF]");
+
+    patch_field(&mut frame, 1, 8, 42u32, 10, Align::Left, b'_');
+    assert_eq!(&frame, b"[42______]");
+}
+
+#[test]
+#[cfg(feature = "i32")]
+fn patched_negative_fields() {
+    let mut frame = *b"[......]";
+    patch_field(&mut frame, 1, 6, -5i32, 10, Align::Right, b'0');
+    assert_eq!(&frame, b"[-00005]");
+
+    patch_field(&mut frame, 1, 6, -12345i32, 10, Align::Right, b'0');
+    assert_eq!(&frame, b"[-12345]");
+
+    patch_field(&mut frame, 1, 6, -5i32, 10, Align::Right, b' ');
+    assert_eq!(&frame, b"[    -5]");
+}
+
+#[test]
+#[should_panic]
+fn patched_id_overflow() {
+    let mut event = *b"id: XX\n";
+    patch_id(&mut event, 4, 2, 100);
+}
@@ -0,0 +1,52 @@
+//! Conversions which hand back a `&str` rather than an indice, for code which passes strings around.
+//!
+//! Writing through a `&mut str` directly is only possible with `unsafe` code, which this crate does not use.
+//! Instead, a byte buffer or a reusable `String` is accepted as the scratch space, and the digits are verified
+//! as UTF-8 before being returned, which costs next to nothing for the handful of ASCII bytes involved.
+//!
+//! ```
+//! use numtoa::text::str_numtoa;
+//!
+//! let mut buffer = [0u8; 20];
+//! let text: &str = str_numtoa(-1234i32, 10, &mut buffer);
+//! assert_eq!(text, "-1234");
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::str;
+
+#[cfg(feature = "alloc")]
+use super::rtl::SCRATCH_LEN;
+use super::NumToA;
+
+/// Writes the number into the end of the byte slice in the given base, and returns the digits as a `&str`.
+///
+/// # Panics
+/// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
+pub fn str_numtoa<T: NumToA<B>, B>(number: T, base: B, string: &mut [u8]) -> &str {
+    let start = number.numtoa(base, string);
+    str::from_utf8(&string[start..]).expect("numtoa: digits are always ASCII")
+}
+
+/// Replaces the contents of the string with the number in the given base, reusing the string's allocation,
+/// and returns the written digits.
+///
+/// # Panics
+/// If the number requires more than 129 bytes, which no primitive integer does, this will panic.
+#[cfg(feature = "alloc")]
+pub fn string_numtoa<T: NumToA<B>, B>(number: T, base: B, string: &mut String) -> &str {
+    let mut scratch = [0u8; SCRATCH_LEN];
+    string.clear();
+    string.push_str(str_numtoa(number, base, &mut scratch));
+    string.as_str()
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "u16"))]
+fn reused_string() {
+    let mut string = String::with_capacity(8);
+    assert_eq!(string_numtoa(65535u16, 16, &mut string), "FFFF");
+    assert_eq!(string_numtoa(7u16, 10, &mut string), "7");
+    assert_eq!(string.capacity(), 8);
+}
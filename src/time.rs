@@ -0,0 +1,240 @@
+//! Formatting of durations for profiling and user interfaces.
+//!
+//! ```
+//! use numtoa::time::format_cycles;
+//!
+//! // 48,000 cycles of a 168 MHz clock.
+//! let mut buffer = [0u8; 24];
+//! let start = format_cycles(48_000, 168_000_000, 2, &mut buffer);
+//! assert_eq!(&buffer[start..], "285.71 µs".as_bytes());
+//! ```
+
+use super::fixed::{convert, Rounding};
+use super::positional::{format_mixed_radix, format_sexagesimal};
+use super::rtl::Rtl;
+
+// The units which nanosecond counts are written in, and the number of nanoseconds within each.
+const NANO_UNITS: [(u128, &str); 4] = [(1, " ns"), (1_000, " µs"), (1_000_000, " ms"), (1_000_000_000, " s")];
+
+// The units which cycle counts are written in, and the number of them within a second.
+const CYCLE_UNITS: [(u128, &str); 3] = [(1_000_000, " µs"), (1_000, " ms"), (1, " s")];
+
+/// Writes the time taken by a number of clock cycles at the given frequency into the end of the byte slice,
+/// using whichever of `µs`, `ms`, or `s` keeps the number short, with `decimals` fractional digits. The result is
+/// computed with 128-bit intermediates and rounded half up. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the frequency is zero, `decimals` is so large that the time no longer fits in a `u128`, or the supplied
+/// buffer is too small to hold the output, this will panic.
+pub fn format_cycles(cycles: u64, hz: u64, decimals: u32, string: &mut [u8]) -> usize {
+    assert!(hz != 0, "numtoa: clock frequency is zero");
+    let (cycles, hz) = (u128::from(cycles), u128::from(hz));
+
+    let mut unit = if cycles >= hz { 2 } else if cycles * 1000 >= hz { 1 } else { 0 };
+    let mut magnitude = convert(cycles, CYCLE_UNITS[unit].0, hz, decimals, Rounding::HalfUp);
+    if unit < 2 && 10u128.checked_pow(decimals).is_some_and(|one| magnitude / 1000 >= one) {
+        unit += 1;
+        magnitude = convert(cycles, CYCLE_UNITS[unit].0, hz, decimals, Rounding::HalfUp);
+    }
+
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(CYCLE_UNITS[unit].1.as_bytes());
+    rtl.fixed(magnitude, decimals);
+    rtl.index()
+}
+
+/// Writes a duration in nanoseconds into the end of the byte slice, using whichever of `ns`, `µs`, `ms`, or `s`
+/// keeps the number short, with three significant digits, such as `1.23 ms`. Nanoseconds are always written as
+/// whole numbers, and durations from a thousand seconds upwards as whole seconds. The result is rounded half up.
+/// Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::time::format_nanos;
+///
+/// let mut buffer = [0u8; 24];
+/// let start = format_nanos(1_234_567, &mut buffer);
+/// assert_eq!(&buffer[start..], b"1.23 ms");
+/// ```
+pub fn format_nanos(nanos: u64, string: &mut [u8]) -> usize {
+    let nanos = u128::from(nanos);
+    let mut unit = NANO_UNITS.iter().rposition(|&(divisor, _)| nanos >= divisor).unwrap_or(0);
+    let whole = nanos / NANO_UNITS[unit].0;
+
+    let mut decimals = match whole {
+        _ if unit == 0 => 0,
+        0..=9 => 2,
+        10..=99 => 1,
+        _ => 0,
+    };
+    let mut magnitude = convert(nanos, 1, NANO_UNITS[unit].0, decimals, Rounding::HalfUp);
+
+    // Rounding up to a fourth significant digit drops a decimal, or moves up to the next unit without decimals.
+    if magnitude == 1000 && decimals > 0 {
+        decimals -= 1;
+        magnitude = 100;
+    } else if magnitude == 1000 && unit < 3 {
+        unit += 1;
+        decimals = 2;
+        magnitude = 100;
+    }
+
+    let mut rtl = Rtl::new(string);
+    rtl.bytes(NANO_UNITS[unit].1.as_bytes());
+    rtl.fixed(magnitude, decimals);
+    rtl.index()
+}
+
+/// Writes a frame count as an SMPTE timecode of `HH:MM:SS:FF` into the end of the byte slice at the given nominal
+/// frame rate, such as 25 or 30, returning the indice where the inner string begins. The hours wrap around after
+/// a day.
+///
+/// With `drop_frame` set, the frame count is taken to be at the NTSC rate of 29.97 or 59.94 frames per second
+/// (given as a nominal rate of 30 or 60), and is written as a drop-frame timecode of `HH:MM:SS;FF`. The first
+/// two (or four) frame numbers of every minute are skipped, except for every tenth minute, so that the timecode
+/// keeps pace with the wall clock.
+///
+/// # Panics
+/// If the frame rate is below 2, if `drop_frame` is set with a rate other than 30 or 60, or if the supplied buffer
+/// is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::time::format_timecode;
+///
+/// let mut buffer = [0u8; 16];
+/// let start = format_timecode(1800, 30, true, &mut buffer);
+/// assert_eq!(&buffer[start..], b"00:01:00;02");
+/// ```
+pub fn format_timecode(frames: u64, fps: u32, drop_frame: bool, string: &mut [u8]) -> usize {
+    let fps = u64::from(fps);
+    if !drop_frame {
+        return format_mixed_radix(frames, &[24, 60, 60, fps], &[b":", b":", b":"], string);
+    }
+
+    assert!(fps == 30 || fps == 60, "numtoa: drop-frame timecode requires a rate of 30 or 60");
+    let dropped = fps / 15;
+    let per_ten_minutes = fps * 600 - dropped * 9;
+    let per_minute = fps * 60 - dropped;
+
+    let (tens, rem) = (frames / per_ten_minutes, frames % per_ten_minutes);
+    let mut labels = frames + dropped * 9 * tens;
+    if rem > dropped {
+        labels += dropped * ((rem - dropped) / per_minute);
+    }
+
+    format_mixed_radix(labels, &[24, 60, 60, fps], &[b":", b":", b";"], string)
+}
+
+/// Writes the time remaining on a countdown into the end of the byte slice, as `M:SS` below an hour and as
+/// `H:MM:SS` from an hour upwards. With `tenths` set, the last minute is written as seconds and tenths of a second
+/// instead, such as `9.5`. The time is rounded down, as a chess clock would. Returns the indice where the inner
+/// string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::time::format_countdown;
+///
+/// let mut buffer = [0u8; 16];
+/// let start = format_countdown(754_999, false, &mut buffer);
+/// assert_eq!(&buffer[start..], b"12:34");
+///
+/// let start = format_countdown(9_550, true, &mut buffer);
+/// assert_eq!(&buffer[start..], b"9.5");
+/// ```
+pub fn format_countdown(remaining_ms: u64, tenths: bool, string: &mut [u8]) -> usize {
+    let seconds = (remaining_ms / 1000) as i64;
+    if tenths && remaining_ms < 60_000 {
+        let mut rtl = Rtl::new(string);
+        rtl.byte(b'0' + (remaining_ms % 1000 / 100) as u8);
+        rtl.byte(b'.');
+        rtl.digits(u128::from(remaining_ms / 1000));
+        rtl.index()
+    } else if seconds < 3600 {
+        format_sexagesimal(seconds, &[1, 2], &[b":"], string)
+    } else {
+        format_sexagesimal(seconds, &[1, 2, 2], &[b":", b":"], string)
+    }
+}
+
+#[test]
+fn cycles_to_time() {
+    let mut buffer = [0u8; 32];
+    let i = format_cycles(1, 1_000_000_000, 3, &mut buffer);
+    assert_eq!(&buffer[i..], "0.001 µs".as_bytes());
+
+    let i = format_cycles(72_000, 72_000_000, 1, &mut buffer);
+    assert_eq!(&buffer[i..], b"1.0 ms");
+
+    let i = format_cycles(71_999_999, 72_000_000, 2, &mut buffer);
+    assert_eq!(&buffer[i..], b"1.00 s");
+
+    let i = format_cycles(u64::MAX, 1, 0, &mut buffer);
+    assert_eq!(&buffer[i..], b"18446744073709551615 s");
+}
+
+#[test]
+fn timecodes() {
+    let mut buffer = [0u8; 16];
+    let i = format_timecode(25 * 3661 + 24, 25, false, &mut buffer);
+    assert_eq!(&buffer[i..], b"01:01:01:24");
+
+    let i = format_timecode(1799, 30, true, &mut buffer);
+    assert_eq!(&buffer[i..], b"00:00:59;29");
+
+    // The tenth minute does not skip any frame numbers.
+    let i = format_timecode(17982, 30, true, &mut buffer);
+    assert_eq!(&buffer[i..], b"00:10:00;00");
+
+    let i = format_timecode(3600, 60, true, &mut buffer);
+    assert_eq!(&buffer[i..], b"00:01:00;04");
+}
+
+#[test]
+#[should_panic(expected = "numtoa: drop-frame timecode requires a rate of 30 or 60")]
+fn drop_frame_rate() {
+    format_timecode(0, 90, true, &mut [0u8; 16]);
+}
+
+#[test]
+fn countdowns() {
+    let mut buffer = [0u8; 24];
+    let i = format_countdown(0, false, &mut buffer);
+    assert_eq!(&buffer[i..], b"0:00");
+
+    let i = format_countdown(59_999, true, &mut buffer);
+    assert_eq!(&buffer[i..], b"59.9");
+
+    let i = format_countdown(60_000, true, &mut buffer);
+    assert_eq!(&buffer[i..], b"1:00");
+
+    let i = format_countdown(3_600_000, false, &mut buffer);
+    assert_eq!(&buffer[i..], b"1:00:00");
+}
+
+#[test]
+fn nanos_to_time() {
+    let mut buffer = [0u8; 32];
+    let cases: [(u64, &str); 10] = [
+        (0, "0 ns"),
+        (999, "999 ns"),
+        (1_000, "1.00 µs"),
+        (9_996, "10.0 µs"),
+        (99_950, "100 µs"),
+        (999_500, "1.00 ms"),
+        (12_345_678, "12.3 ms"),
+        (999_999_999, "1.00 s"),
+        (999_500_000_000, "1000 s"),
+        (u64::MAX, "18446744074 s"),
+    ];
+    for &(nanos, expected) in &cases {
+        let i = format_nanos(nanos, &mut buffer);
+        assert_eq!(&buffer[i..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,168 @@
+//! Output of numbers in the alternative forms of the digits which Unicode provides, written as UTF-8, so that
+//! display code can typeset exponents and CJK layouts, or show the native digits of a localized UI, without
+//! carrying a Unicode table of its own.
+//!
+//! ```
+//! # #[cfg(feature = "u8")] {
+//! use numtoa::unicode::{numtoa_unicode, DigitForm};
+//!
+//! let mut buffer = [0u8; 16];
+//! let start = numtoa_unicode(123u8, 10, DigitForm::Superscript, &mut buffer);
+//! assert_eq!(&buffer[start..], "¹²³".as_bytes());
+//! # }
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "u16")] {
+//! use numtoa::unicode::{numtoa_script, Script};
+//!
+//! let mut buffer = [0u8; 16];
+//! let start = numtoa_script(2024u16, 10, Script::Devanagari, &mut buffer);
+//! assert_eq!(&buffer[start..], "२०२४".as_bytes());
+//! # }
+//! ```
+
+use super::rtl::{Rtl, SCRATCH_LEN};
+use super::NumToA;
+
+/// The form in which the digits are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DigitForm {
+    /// Fullwidth digits and letters, such as `１２３`, which take the width of an ideograph.
+    #[default]
+    Fullwidth,
+    /// Superscript digits, such as `¹²³`, for exponents.
+    Superscript,
+    /// Subscript digits, such as `₁₂₃`, for indices and chemical formulas.
+    Subscript,
+}
+
+impl DigitForm {
+    // The character of an ASCII digit, letter, or minus sign in this form.
+    fn char(self, byte: u8) -> char {
+        let code = match (self, byte) {
+            (DigitForm::Fullwidth, b'-')           => 0xFF0D,
+            (DigitForm::Fullwidth, _)              => 0xFF00 + u32::from(byte) - 0x20,
+            (DigitForm::Superscript, b'-')         => 0x207B,
+            (DigitForm::Superscript, b'1')         => 0x00B9,
+            (DigitForm::Superscript, b'2' | b'3') => 0x00B0 + u32::from(byte - b'0'),
+            (DigitForm::Superscript, b'0'..=b'9') => 0x2070 + u32::from(byte - b'0'),
+            (DigitForm::Subscript, b'-')           => 0x208B,
+            (DigitForm::Subscript, b'0'..=b'9')   => 0x2080 + u32::from(byte - b'0'),
+            _ => panic!("numtoa: superscripts and subscripts only have decimal digits"),
+        };
+        char::from_u32(code).unwrap()
+    }
+}
+
+/// The script whose decimal digits are written in place of the ASCII digits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// The ASCII digits, written unchanged.
+    #[default]
+    Latin,
+    /// The Arabic-Indic digits `٠١٢٣٤٥٦٧٨٩`, as used with Arabic.
+    ArabicIndic,
+    /// The Extended Arabic-Indic digits `۰۱۲۳۴۵۶۷۸۹`, as used with Persian and Urdu.
+    ExtendedArabicIndic,
+    /// The Devanagari digits `०१२३४५६७८९`, as used with Hindi and Marathi.
+    Devanagari,
+    /// The Bengali digits `০১২৩৪৫৬৭৮৯`.
+    Bengali,
+    /// The Thai digits `๐๑๒๓๔๕๖๗๘๙`.
+    Thai,
+    /// The Khmer digits `០១២៣៤៥៦៧៨៩`.
+    Khmer,
+}
+
+impl Script {
+    /// The digit zero of the script, which the other nine digits follow in order.
+    pub fn zero(self) -> char {
+        let code = match self {
+            Script::Latin               => 0x0030,
+            Script::ArabicIndic         => 0x0660,
+            Script::ExtendedArabicIndic => 0x06F0,
+            Script::Devanagari          => 0x0966,
+            Script::Bengali             => 0x09E6,
+            Script::Thai                => 0x0E50,
+            Script::Khmer               => 0x17E0,
+        };
+        char::from_u32(code).unwrap()
+    }
+
+    // The character of an ASCII digit or minus sign in this script.
+    fn char(self, byte: u8) -> char {
+        match byte {
+            b'-' => '-',
+            b'0'..=b'9' => char::from_u32(self.zero() as u32 + u32::from(byte - b'0')).unwrap(),
+            _ if self == Script::Latin => char::from(byte),
+            _ => panic!("numtoa: scripts only have decimal digits"),
+        }
+    }
+}
+
+// Writes the digits of the number into the end of the byte slice, mapping each ASCII byte to a character.
+fn write_mapped<T: NumToA<B>, B>(number: T, base: B, string: &mut [u8], map: impl Fn(u8) -> char) -> usize {
+    let mut scratch = [0u8; SCRATCH_LEN];
+    let start = number.numtoa(base, &mut scratch);
+    let mut rtl = Rtl::new(string);
+    scratch[start..].iter().rev().for_each(|&byte| rtl.char(map(byte)));
+    rtl.index()
+}
+
+/// Writes the number in the given base into the end of the byte slice in the given form of the digits, as UTF-8.
+/// Fullwidth digits cover every base, while superscripts and subscripts only cover bases up to 10. Returns the
+/// indice where the inner string begins.
+///
+/// # Panics
+/// If a digit has no superscript or subscript, or the supplied buffer is too small to hold the output, which
+/// takes up to three bytes for each digit, this will panic.
+pub fn numtoa_unicode<T: NumToA<B>, B>(number: T, base: B, form: DigitForm, string: &mut [u8]) -> usize {
+    write_mapped(number, base, string, |byte| form.char(byte))
+}
+
+/// Writes the number in the given base into the end of the byte slice with the digits of the given script, as
+/// UTF-8, keeping the ASCII minus sign. Only the Latin script covers bases above 10. Returns the indice where the
+/// inner string begins.
+///
+/// # Panics
+/// If a digit has no equivalent in the script, or the supplied buffer is too small to hold the output, which
+/// takes up to three bytes for each digit, this will panic.
+pub fn numtoa_script<T: NumToA<B>, B>(number: T, base: B, script: Script, string: &mut [u8]) -> usize {
+    write_mapped(number, base, string, |byte| script.char(byte))
+}
+
+#[test]
+#[cfg(feature = "i32")]
+fn unicode_forms() {
+    let mut buffer = [0u8; 64];
+    let i = numtoa_unicode(-1_234_567_890i32, 10, DigitForm::Superscript, &mut buffer);
+    assert_eq!(&buffer[i..], "⁻¹²³⁴⁵⁶⁷⁸⁹⁰".as_bytes());
+
+    let i = numtoa_unicode(-1_234_567_890i32, 10, DigitForm::Subscript, &mut buffer);
+    assert_eq!(&buffer[i..], "₋₁₂₃₄₅₆₇₈₉₀".as_bytes());
+
+    let i = numtoa_unicode(-0xBEEFi32, 16, DigitForm::Fullwidth, &mut buffer);
+    assert_eq!(&buffer[i..], "－ＢＥＥＦ".as_bytes());
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "u8")]
+fn unicode_superscript_hex() {
+    numtoa_unicode(0xAu8, 16, DigitForm::Superscript, &mut [0u8; 16]);
+}
+
+#[test]
+#[cfg(feature = "i32")]
+fn unicode_scripts() {
+    let mut buffer = [0u8; 64];
+    let i = numtoa_script(-1_234_567_890i32, 10, Script::ArabicIndic, &mut buffer);
+    assert_eq!(&buffer[i..], "-١٢٣٤٥٦٧٨٩٠".as_bytes());
+
+    let i = numtoa_script(9_876i32, 10, Script::Thai, &mut buffer);
+    assert_eq!(&buffer[i..], "๙๘๗๖".as_bytes());
+
+    let i = numtoa_script(-0xFFi32, 16, Script::Latin, &mut buffer);
+    assert_eq!(&buffer[i..], b"-FF");
+}
@@ -0,0 +1,380 @@
+//! Presets for physical quantities which firmware commonly stores as scaled integers, written with their unit.
+//!
+//! ```
+//! use numtoa::units::format_millivolts;
+//!
+//! let mut buffer = [0u8; 16];
+//! let start = format_millivolts(3300, &mut buffer);
+//! assert_eq!(&buffer[start..], b"3.300 V");
+//! ```
+//!
+//! Downstream crates define units of their own with `Unit`, which every preset here is built from.
+//!
+//! ```
+//! use numtoa::units::{format_unit, Unit};
+//!
+//! // A flow meter which counts 450 pulses per litre.
+//! const LITRES: Unit = Unit::new(1, 450, 2, b" L");
+//!
+//! let mut buffer = [0u8; 16];
+//! let start = format_unit(1_000, &LITRES, &mut buffer);
+//! assert_eq!(&buffer[start..], b"2.22 L");
+//! ```
+//!
+//! Whole numbers are written with any unit through `numtoa_suffixed`.
+//!
+//! ```
+//! use numtoa::units::numtoa_suffixed;
+//!
+//! let mut buffer = [0u8; 16];
+//! assert_eq!(numtoa_suffixed(1013u16, 10, b"hPa", &mut buffer), b"1013hPa");
+//! ```
+
+use super::fixed::{write_converted, Rounding};
+use super::rtl::Rtl;
+use super::NumToA;
+
+/// Writes the number in the given base into the end of the byte slice followed by the unit, such as `42ms`, and
+/// returns the written bytes.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the number and the unit, this will panic.
+pub fn numtoa_suffixed<'a, T: NumToA<B>, B>(number: T, base: B, unit: &[u8], string: &'a mut [u8]) -> &'a [u8] {
+    let split = string.len().checked_sub(unit.len()).expect("numtoa: buffer is too small");
+    string[split..].copy_from_slice(unit);
+    let start = number.numtoa(base, &mut string[..split]);
+    &string[start..]
+}
+
+/// A unit which integers are converted into before they are written, as `value * numerator / denominator` with
+/// `decimals` fractional digits, followed by the suffix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Unit {
+    /// The numerator of the factor which converts a value into this unit.
+    pub numerator: u64,
+    /// The denominator of the factor which converts a value into this unit.
+    pub denominator: u64,
+    /// The number of fractional digits which are written.
+    pub decimals: u32,
+    /// The bytes written after the number, such as ` V`.
+    pub suffix: &'static [u8],
+}
+
+impl Unit {
+    /// Describes a unit, such as `Unit::new(1, 1000, 3, b" V")` for millivolts written as volts.
+    pub const fn new(numerator: u64, denominator: u64, decimals: u32, suffix: &'static [u8]) -> Self {
+        Unit { numerator, denominator, decimals, suffix }
+    }
+}
+
+/// Millivolts written as volts, such as `3.300 V`.
+pub const VOLTS: Unit = Unit::new(1, 1000, 3, b" V");
+/// Milliamps written as amps, such as `-0.125 A`.
+pub const AMPS: Unit = Unit::new(1, 1000, 3, b" A");
+/// Milliwatts written as watts, such as `1.250 W`.
+pub const WATTS: Unit = Unit::new(1, 1000, 3, b" W");
+
+// Writes the value converted into the unit, followed by its suffix, which every preset in this module is built
+// from. The conversion is exact until the last digit, and a value which rounds to zero is written without a sign.
+fn write_unit(rtl: &mut Rtl, value: i64, unit: &Unit, rounding: Rounding) {
+    assert!(unit.denominator != 0, "numtoa: unit denominator is zero");
+    rtl.bytes(unit.suffix);
+    write_converted(rtl, value, u128::from(unit.numerator), u128::from(unit.denominator), unit.decimals, rounding);
+}
+
+/// Writes the value converted into the unit into the end of the byte slice, followed by the unit's suffix, with
+/// the last digit rounded half up. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the denominator of the unit is zero, if the conversion overflows a `u128` before it is divided, or if the
+/// supplied buffer is too small to hold the output, this will panic.
+pub fn format_unit(value: i64, unit: &Unit, string: &mut [u8]) -> usize {
+    let mut rtl = Rtl::new(string);
+    write_unit(&mut rtl, value, unit, Rounding::HalfUp);
+    rtl.index()
+}
+
+/// Writes a millivolt reading as volts, such as `3.300 V`, returning the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_millivolts(millivolts: i32, string: &mut [u8]) -> usize {
+    format_unit(i64::from(millivolts), &VOLTS, string)
+}
+
+/// Writes a milliamp reading as amps, such as `-0.125 A`, returning the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_milliamps(milliamps: i32, string: &mut [u8]) -> usize {
+    format_unit(i64::from(milliamps), &AMPS, string)
+}
+
+/// Writes a milliwatt reading as watts, such as `1.250 W`, returning the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_milliwatts(milliwatts: i32, string: &mut [u8]) -> usize {
+    format_unit(i64::from(milliwatts), &WATTS, string)
+}
+
+/// How the unit of a temperature is written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DegreeSymbol {
+    /// Writes the unit with the UTF-8 degree sign, such as `23.45°C`.
+    #[default]
+    Unicode,
+    /// Writes the unit in plain ASCII, such as `23.45C`, for displays without a degree glyph.
+    Ascii,
+    /// Writes the number alone.
+    None,
+}
+
+impl DegreeSymbol {
+    fn celsius(self) -> &'static [u8] {
+        match self {
+            DegreeSymbol::Unicode => "°C".as_bytes(),
+            DegreeSymbol::Ascii   => b"C",
+            DegreeSymbol::None    => b"",
+        }
+    }
+
+    fn fahrenheit(self) -> &'static [u8] {
+        match self {
+            DegreeSymbol::Unicode => "°F".as_bytes(),
+            DegreeSymbol::Ascii   => b"F",
+            DegreeSymbol::None    => b"",
+        }
+    }
+}
+
+/// Writes a temperature in hundredths of a degree Celsius, as most temperature sensors report it, such as
+/// `23.45°C`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_centidegrees(centidegrees: i32, symbol: DegreeSymbol, string: &mut [u8]) -> usize {
+    format_unit(i64::from(centidegrees), &Unit::new(1, 100, 2, symbol.celsius()), string)
+}
+
+/// Writes a temperature in hundredths of a degree Celsius as both Celsius and Fahrenheit, such as
+/// `22.5°C / 72.5°F`, with `decimals` fractional digits each. The Fahrenheit reading is rounded half away from
+/// zero from the exact conversion, rather than from the rounded Celsius reading. Returns the indice where the
+/// inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::units::{format_centidegrees_dual, DegreeSymbol};
+///
+/// let mut buffer = [0u8; 32];
+/// let start = format_centidegrees_dual(2250, 1, DegreeSymbol::Unicode, &mut buffer);
+/// assert_eq!(&buffer[start..], "22.5°C / 72.5°F".as_bytes());
+/// ```
+pub fn format_centidegrees_dual(centidegrees: i32, decimals: u32, symbol: DegreeSymbol, string: &mut [u8]) -> usize {
+    // The Fahrenheit temperature in fifths of a hundredth of a degree, which is exact.
+    let fifths = i64::from(centidegrees) * 9 + 16_000;
+
+    let mut rtl = Rtl::new(string);
+    write_unit(&mut rtl, fifths, &Unit::new(1, 500, decimals, symbol.fahrenheit()), Rounding::HalfUp);
+    rtl.bytes(b" / ");
+    write_unit(&mut rtl, i64::from(centidegrees), &Unit::new(1, 100, decimals, symbol.celsius()), Rounding::HalfUp);
+    rtl.index()
+}
+
+/// The unit which a pressure is written in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PressureUnit {
+    /// Pascals, such as `101325 Pa`.
+    Pascal,
+    /// Hectopascals, or millibars, as weather reports give them, such as `1013.25 hPa`.
+    #[default]
+    Hectopascal,
+    /// Inches of mercury at 0°C, as American weather reports give them, such as `29.92 inHg`.
+    InchesOfMercury,
+    /// Pounds per square inch, such as `14.70 psi`.
+    Psi,
+}
+
+impl PressureUnit {
+    // The conversion from pascals into this unit.
+    fn unit(self, decimals: u32) -> Unit {
+        match self {
+            PressureUnit::Pascal          => Unit::new(1, 1, decimals, b" Pa"),
+            PressureUnit::Hectopascal     => Unit::new(1, 100, decimals, b" hPa"),
+            PressureUnit::InchesOfMercury => Unit::new(1000, 3_386_389, decimals, b" inHg"),
+            PressureUnit::Psi             => Unit::new(1_000_000_000, 6_894_757_293_168, decimals, b" psi"),
+        }
+    }
+}
+
+/// Writes a pressure in whole pascals, as barometric sensors report it, converted to the given unit with
+/// `decimals` fractional digits, such as `29.92 inHg`. The conversion is exact until the last digit, which is
+/// rounded according to the rounding mode, and a pressure which rounds to zero is written without a sign. Returns
+/// the indice where the inner string begins.
+///
+/// # Panics
+/// If `decimals` is above 9, or the supplied buffer is too small to hold the output, this will panic.
+///
+/// # Example
+/// ```
+/// use numtoa::fixed::Rounding;
+/// use numtoa::units::{format_pressure, PressureUnit};
+///
+/// let mut buffer = [0u8; 24];
+/// let start = format_pressure(101_325, PressureUnit::InchesOfMercury, 2, Rounding::HalfUp, &mut buffer);
+/// assert_eq!(&buffer[start..], b"29.92 inHg");
+/// ```
+pub fn format_pressure(
+    pascals: i64,
+    unit: PressureUnit,
+    decimals: u32,
+    rounding: Rounding,
+    string: &mut [u8],
+) -> usize {
+    assert!(decimals <= 9, "numtoa: at most nine decimals are supported");
+    let mut rtl = Rtl::new(string);
+    write_unit(&mut rtl, pascals, &unit.unit(decimals), rounding);
+    rtl.index()
+}
+
+/// The reference which a level in decibels is relative to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DecibelUnit {
+    /// A ratio without a reference, such as a gain of `6.02 dB`.
+    #[default]
+    Db,
+    /// A power relative to one milliwatt, such as a received signal strength of `-73.50 dBm`.
+    Dbm,
+    /// A level relative to the full scale of a converter, such as `-3.00 dBFS`.
+    Dbfs,
+}
+
+impl DecibelUnit {
+    fn suffix(self) -> &'static [u8] {
+        match self {
+            DecibelUnit::Db   => b" dB",
+            DecibelUnit::Dbm  => b" dBm",
+            DecibelUnit::Dbfs => b" dBFS",
+        }
+    }
+}
+
+/// Writes a level in hundredths of a decibel, as radio and audio firmware commonly stores it, such as
+/// `-73.50 dBm`. Returns the indice where the inner string begins.
+///
+/// # Panics
+/// If the supplied buffer is too small to hold the output, this will panic.
+pub fn format_centidecibels(centidecibels: i32, unit: DecibelUnit, string: &mut [u8]) -> usize {
+    format_unit(i64::from(centidecibels), &Unit::new(1, 100, 2, unit.suffix()), string)
+}
+
+#[test]
+#[cfg(all(feature = "u8", feature = "i32"))]
+fn suffixed_numbers() {
+    let mut buffer = [0u8; 16];
+    assert_eq!(numtoa_suffixed(87u8, 10, b"%", &mut buffer), b"87%");
+    assert_eq!(numtoa_suffixed(-42i32, 10, b" ms", &mut buffer), b"-42 ms");
+    assert_eq!(numtoa_suffixed(0u8, 16, b"", &mut buffer), b"0");
+}
+
+#[test]
+fn electrical_units() {
+    let mut buffer = [0u8; 16];
+    let i = format_millivolts(5, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.005 V");
+
+    let i = format_milliamps(-125, &mut buffer);
+    assert_eq!(&buffer[i..], b"-0.125 A");
+
+    let i = format_milliwatts(i32::MIN, &mut buffer);
+    assert_eq!(&buffer[i..], b"-2147483.648 W");
+}
+
+#[test]
+fn temperatures() {
+    let mut buffer = [0u8; 16];
+    let i = format_centidegrees(2345, DegreeSymbol::Unicode, &mut buffer);
+    assert_eq!(&buffer[i..], "23.45°C".as_bytes());
+
+    let i = format_centidegrees(-5, DegreeSymbol::Ascii, &mut buffer);
+    assert_eq!(&buffer[i..], b"-0.05C");
+
+    let i = format_centidegrees(10000, DegreeSymbol::None, &mut buffer);
+    assert_eq!(&buffer[i..], b"100.00");
+}
+
+#[test]
+fn dual_temperatures() {
+    let mut buffer = [0u8; 40];
+    let i = format_centidegrees_dual(-4000, 0, DegreeSymbol::Ascii, &mut buffer);
+    assert_eq!(&buffer[i..], b"-40C / -40F");
+
+    // 21.11°C is 69.998°F, which rounds up to a whole degree.
+    let i = format_centidegrees_dual(2111, 2, DegreeSymbol::Ascii, &mut buffer);
+    assert_eq!(&buffer[i..], b"21.11C / 70.00F");
+
+    // -17.78°C is -0.004°F, which rounds to zero without a sign.
+    let i = format_centidegrees_dual(-1778, 2, DegreeSymbol::None, &mut buffer);
+    assert_eq!(&buffer[i..], b"-17.78 / 0.00");
+
+    let i = format_centidegrees_dual(i32::MIN, 1, DegreeSymbol::Unicode, &mut buffer);
+    assert_eq!(&buffer[i..], "-21474836.5°C / -38654673.7°F".as_bytes());
+}
+
+#[test]
+fn decibels() {
+    let mut buffer = [0u8; 16];
+    let i = format_centidecibels(-7350, DecibelUnit::Dbm, &mut buffer);
+    assert_eq!(&buffer[i..], b"-73.50 dBm");
+
+    let i = format_centidecibels(-1, DecibelUnit::Dbfs, &mut buffer);
+    assert_eq!(&buffer[i..], b"-0.01 dBFS");
+
+    let i = format_centidecibels(602, DecibelUnit::Db, &mut buffer);
+    assert_eq!(&buffer[i..], b"6.02 dB");
+}
+
+#[test]
+fn pressures() {
+    let mut buffer = [0u8; 40];
+    let i = format_pressure(101_325, PressureUnit::Hectopascal, 2, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"1013.25 hPa");
+
+    let i = format_pressure(101_325, PressureUnit::Psi, 3, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"14.696 psi");
+
+    let i = format_pressure(101_325, PressureUnit::Psi, 3, Rounding::Truncate, &mut buffer);
+    assert_eq!(&buffer[i..], b"14.695 psi");
+
+    let i = format_pressure(-5, PressureUnit::Pascal, 0, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"-5 Pa");
+
+    let i = format_pressure(-1, PressureUnit::InchesOfMercury, 2, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.00 inHg");
+
+    let i = format_pressure(i64::MIN, PressureUnit::Hectopascal, 9, Rounding::HalfUp, &mut buffer);
+    assert_eq!(&buffer[i..], b"-92233720368547758.080000000 hPa");
+}
+
+#[test]
+fn custom_units() {
+    const KILOMETRES: Unit = Unit::new(1, 1000, 1, b" km");
+    let mut buffer = [0u8; 48];
+    let i = format_unit(-1_250, &KILOMETRES, &mut buffer);
+    assert_eq!(&buffer[i..], b"-1.3 km");
+
+    let i = format_unit(-49, &KILOMETRES, &mut buffer);
+    assert_eq!(&buffer[i..], b"0.0 km");
+
+    let i = format_unit(i64::MIN, &Unit::new(u64::MAX, 1, 0, b""), &mut buffer);
+    assert_eq!(&buffer[i..], b"-170141183460469231722463931679029329920");
+}
+
+#[test]
+#[should_panic]
+fn unit_overflow() {
+    format_unit(i64::MIN, &Unit::new(u64::MAX, 1, 1, b""), &mut [0u8; 64]);
+}
@@ -0,0 +1,65 @@
+//! `NumToA` implementations for the 256-bit integers of the `ethnum` and `primitive-types` crates. The limbs are
+//! copied onto the stack, so no allocator is needed.
+//!
+//! A 256-bit number requires at most 78 bytes in base 10, or 79 bytes when signed.
+
+use super::limbs::limbs_numtoa;
+use super::NumToA;
+
+#[cfg(feature = "ethnum")]
+mod ethnum_impls {
+    use super::{limbs_numtoa, NumToA};
+    use ethnum::{I256, U256};
+
+    impl NumToA<u32> for U256 {
+        fn numtoa(self, base: u32, string: &mut [u8]) -> usize {
+            let (hi, lo) = self.into_words();
+            let mut limbs = [lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64];
+            limbs_numtoa(&mut limbs, false, base, string)
+        }
+    }
+
+    impl NumToA<u32> for I256 {
+        fn numtoa(self, base: u32, string: &mut [u8]) -> usize {
+            let (hi, lo) = self.unsigned_abs().into_words();
+            let mut limbs = [lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64];
+            limbs_numtoa(&mut limbs, self.is_negative(), base, string)
+        }
+    }
+
+    #[test]
+    fn ethnum_conversions() {
+        let mut buffer = [0u8; 79];
+        let i = U256::MAX.numtoa(10, &mut buffer);
+        assert_eq!(&buffer[i..], &b"115792089237316195423570985008687907853269984665640564039457584007913129639935"[..]);
+
+        let i = I256::MIN.numtoa(16, &mut buffer);
+        assert_eq!(&buffer[i..], &b"-8000000000000000000000000000000000000000000000000000000000000000"[..]);
+
+        let i = I256::new(-1).numtoa(10, &mut buffer);
+        assert_eq!(&buffer[i..], b"-1");
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+mod primitive_types_impls {
+    use super::{limbs_numtoa, NumToA};
+    use primitive_types::U256;
+
+    impl NumToA<u32> for U256 {
+        fn numtoa(self, base: u32, string: &mut [u8]) -> usize {
+            let U256(mut limbs) = self;
+            limbs_numtoa(&mut limbs, false, base, string)
+        }
+    }
+
+    #[test]
+    fn primitive_types_conversions() {
+        let mut buffer = [0u8; 78];
+        let i = U256::MAX.numtoa(10, &mut buffer);
+        assert_eq!(&buffer[i..], &b"115792089237316195423570985008687907853269984665640564039457584007913129639935"[..]);
+
+        let i = U256::from(1_000_000_000_000_000_000u64).numtoa(10, &mut buffer);
+        assert_eq!(&buffer[i..], b"1000000000000000000");
+    }
+}